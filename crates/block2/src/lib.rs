@@ -96,6 +96,33 @@
 //! Thread-safe blocks are not yet representable in `block2`, and as such any
 //! function that requires a thread-safe block must be marked `unsafe`.
 //!
+//! This matters in particular for completion handlers, which are commonly
+//! invoked on a background thread or queue (`NSURLSession` is one example).
+//! Since the block types do not implement [`Send`] or [`Sync`], the compiler
+//! cannot check this for you, so when calling such a function, you must
+//! ensure yourself that the closure (and everything it captures) is
+//! [`Send`] and [`Sync`], e.g. by using [`Arc`] and `Mutex` instead of
+//! [`Rc`] and [`RefCell`].
+//!
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use block2::RcBlock;
+//!
+//! let result = Arc::new(Mutex::new(None));
+//! let result_clone = Arc::clone(&result);
+//! let completion_handler = RcBlock::new(move |value: i32| {
+//!     *result_clone.lock().unwrap() = Some(value);
+//! });
+//!
+//! // Pass the block to an API that may call it on another thread.
+//! // For demonstration purposes, we call it ourselves.
+//! completion_handler.call((42,));
+//! assert_eq!(*result.lock().unwrap(), Some(42));
+//! ```
+//!
+//! [`Arc`]: alloc::sync::Arc
+//! [`Rc`]: alloc::rc::Rc
+//!
 //!
 //! ## Mutability
 //!
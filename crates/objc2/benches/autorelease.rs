@@ -1,12 +1,17 @@
 use core::ffi::c_void;
-use core::mem::ManuallyDrop;
+use core::mem::{self, ManuallyDrop};
+use std::sync::OnceLock;
 
-use objc2::rc::{autoreleasepool, Retained};
-use objc2::runtime::{AnyClass, NSObject, Sel};
+use objc2::rc::{autoreleasepool, autoreleasepool_leaking, Retained};
+use objc2::runtime::__nsstring::{nsstring_to_str, UTF8_ENCODING};
+use objc2::runtime::{AnyClass, Imp, NSObject, Sel};
 use objc2::{class, msg_send, sel};
 
 const BYTES: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
+// Long enough that it won't be stored in a tagged pointer.
+const STRING: &str = "a string that is not a tagged pointer";
+
 fn empty() {}
 
 fn pool_cleanup() {
@@ -25,6 +30,22 @@ fn send_message() -> &'static AnyClass {
     unsafe { msg_send![class!(NSObject), class] }
 }
 
+fn send_message_hash() -> usize {
+    unsafe { msg_send![class!(NSObject), hash] }
+}
+
+fn cached_imp_hash() -> usize {
+    static IMP: OnceLock<Imp> = OnceLock::new();
+    let imp = *IMP.get_or_init(|| {
+        class!(NSObject)
+            .metaclass()
+            .method_implementation(sel!(hash))
+            .unwrap()
+    });
+    let hash: unsafe extern "C-unwind" fn(&AnyClass, Sel) -> usize = unsafe { mem::transmute(imp) };
+    unsafe { hash(class!(NSObject), sel!(hash)) }
+}
+
 fn alloc_nsobject() -> *mut NSObject {
     unsafe { msg_send![class!(NSObject), alloc] }
 }
@@ -79,6 +100,32 @@ fn autoreleased_nsstring() -> *const NSObject {
     unsafe { msg_send![new_leaked_nsstring(), autorelease] }
 }
 
+fn nsstring_with_contents(class: &AnyClass) -> Retained<NSObject> {
+    let bytes_ptr: *const c_void = STRING.as_ptr().cast();
+    let obj: *mut NSObject = unsafe { msg_send![class, alloc] };
+    let obj: *mut NSObject = unsafe {
+        msg_send![
+            obj,
+            initWithBytes: bytes_ptr,
+            length: STRING.len(),
+            encoding: UTF8_ENCODING,
+        ]
+    };
+    unsafe { Retained::from_raw(obj).unwrap_unchecked() }
+}
+
+/// `UTF8String` on an immutable UTF-8 string returns the internal pointer.
+fn nsstring_to_str_immutable() -> usize {
+    let obj = nsstring_with_contents(class!(NSString));
+    autoreleasepool(|pool| unsafe { nsstring_to_str(&obj, pool) }.len())
+}
+
+/// `UTF8String` on a mutable string allocates and copies.
+fn nsstring_to_str_mutable() -> usize {
+    let obj = nsstring_with_contents(class!(NSMutableString));
+    autoreleasepool(|pool| unsafe { nsstring_to_str(&obj, pool) }.len())
+}
+
 fn retain_autoreleased(obj: *const NSObject) -> Retained<NSObject> {
     unsafe { Retained::retain_autoreleased((obj as *mut NSObject).cast()).unwrap_unchecked() }
 }
@@ -107,6 +154,24 @@ fn autoreleased_nsstring_fast_caller_cleanup_pool_cleanup() -> Retained<NSObject
     autoreleasepool(|_| retain_autoreleased(autoreleased_nsstring()))
 }
 
+const LOOP_ITERATIONS: usize = 100;
+
+/// Push and pop a pool on every iteration.
+fn autoreleased_nsstring_loop_pool_per_iteration() {
+    for _ in 0..LOOP_ITERATIONS {
+        autoreleasepool(|_| iai::black_box(autoreleased_nsstring()));
+    }
+}
+
+/// Reuse a single pool for all iterations.
+fn autoreleased_nsstring_loop_single_pool() {
+    autoreleasepool(|_| {
+        for _ in 0..LOOP_ITERATIONS {
+            autoreleasepool_leaking(|_| iai::black_box(autoreleased_nsstring()));
+        }
+    });
+}
+
 macro_rules! main_with_warmup {
     ($($f:ident,)+) => {
         mod warmup_fns {
@@ -141,6 +206,8 @@ main_with_warmup! {
     class,
     sel,
     send_message,
+    send_message_hash,
+    cached_imp_hash,
     alloc_nsobject,
     new_nsobject,
     // NSData
@@ -157,4 +224,9 @@ main_with_warmup! {
     autoreleased_nsstring_pool_cleanup,
     autoreleased_nsstring_fast_caller_cleanup,
     autoreleased_nsstring_fast_caller_cleanup_pool_cleanup,
+    nsstring_to_str_immutable,
+    nsstring_to_str_mutable,
+    // Loops
+    autoreleased_nsstring_loop_pool_per_iteration,
+    autoreleased_nsstring_loop_single_pool,
 }
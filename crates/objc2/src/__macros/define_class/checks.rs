@@ -18,7 +18,7 @@ use crate::{AnyThread, ClassType, DefinedClass, Message, ProtocolType};
 use super::super::{CopyFamily, InitFamily, MutableCopyFamily, NewFamily, NoneFamily};
 use super::ivars::{
     drop_flag_offset, ivar_drop_flag_names, ivars_offset, register_drop_flag, register_ivars,
-    setup_dealloc,
+    setup_dealloc, DefinedIvarsHelper,
 };
 
 /// Helper for determining auto traits of defined classes.
@@ -217,6 +217,34 @@ fn class_not_unique(c_name: &CStr) -> ! {
     panic!("could not create new class {c_name:?}, perhaps a class with that name already exists?")
 }
 
+#[track_caller]
+fn class_not_compatible(c_name: &CStr, reason: &str) -> ! {
+    panic!(
+        "found existing class {c_name:?}, but it is incompatible with the new definition: {reason}"
+    )
+}
+
+/// Verify that an already registered class looks like the one we would have
+/// created ourselves, such that we can reuse it.
+#[track_caller]
+fn verify_existing_class<T: DefinedClass>(
+    cls: &AnyClass,
+    superclass: &AnyClass,
+    c_name: &CStr,
+    ivar_name: &CStr,
+    drop_flag_name: &CStr,
+) {
+    if cls.superclass() != Some(superclass) {
+        class_not_compatible(c_name, "superclass differs");
+    }
+    if T::HAS_IVARS && cls.instance_variable(ivar_name).is_none() {
+        class_not_compatible(c_name, "instance variables are missing");
+    }
+    if T::HAS_DROP_FLAG && cls.instance_variable(drop_flag_name).is_none() {
+        class_not_compatible(c_name, "drop flag is missing");
+    }
+}
+
 #[inline]
 #[track_caller]
 #[allow(clippy::new_without_default)]
@@ -289,9 +317,15 @@ where
         //
         // We also have an (intentionally undocumented) workaround env var in
         // case this becomes a problem for users in the future.
+        //
+        // Either way, we verify that the existing class is at least somewhat
+        // compatible with what we would have created, to catch the cases
+        // where the names do end up colliding.
         let overridden = option_env!("UNSAFE_OBJC2_ALLOW_CLASS_OVERRIDE") == Some("1");
         if name_is_auto_generated || overridden {
-            AnyClass::get(c_name).unwrap_or_else(|| class_not_present(c_name))
+            let cls = AnyClass::get(c_name).unwrap_or_else(|| class_not_present(c_name));
+            verify_existing_class::<T>(cls, superclass, c_name, &ivar_name, &drop_flag_name);
+            cls
         } else {
             class_not_unique(c_name)
         }
@@ -508,3 +542,44 @@ impl<T: DefinedClass> ClassProtocolMethodsBuilder<'_, T> {
     #[cfg(not(debug_assertions))]
     pub fn finish(self) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::ffi::CString;
+
+    use super::*;
+    use crate::define_class;
+    use crate::runtime::NSObject;
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        struct RegisteredTwice;
+    );
+
+    define_class!(
+        #[unsafe(super(RegisteredTwice))]
+        struct RegisteredTwiceSubclass;
+    );
+
+    #[test]
+    fn reuse_existing_class() {
+        let cls = RegisteredTwice::class();
+        let c_name = CString::new(RegisteredTwice::NAME).unwrap();
+        let (existing, _, _) = define_class::<RegisteredTwice>(&c_name, true, |_| {
+            unreachable!("should not define the class again")
+        });
+        assert_eq!(existing, cls);
+        assert_eq!(existing, RegisteredTwice::class());
+    }
+
+    #[test]
+    #[should_panic = "incompatible with the new definition: superclass differs"]
+    fn reuse_incompatible_class() {
+        let _ = RegisteredTwiceSubclass::class();
+        // Try to define the subclass with the name of the superclass.
+        let c_name = CString::new(RegisteredTwice::NAME).unwrap();
+        let _ = define_class::<RegisteredTwiceSubclass>(&c_name, true, |_| {
+            unreachable!("should not define the class again")
+        });
+    }
+}
@@ -92,13 +92,32 @@ use crate::runtime::Sel;
 /// assert_eq!(sel1, sel2);
 /// ```
 ///
-/// Invalid selector:
+/// Selectors are validated at compile-time, so invalid selectors fail to
+/// compile:
 ///
 /// ```compile_fail
 /// # use objc2::sel;
 /// let sel = sel!(aSelector:withoutTrailingColon);
 /// ```
 ///
+/// ```compile_fail
+/// # use objc2::sel;
+/// let sel = sel!(init object);
+/// ```
+///
+/// ```compile_fail
+/// # use objc2::sel;
+/// let sel = sel!(with-dash:);
+/// ```
+///
+/// ```compile_fail
+/// # use objc2::sel;
+/// let sel = sel!("initWithFrame:");
+/// ```
+///
+/// If you need to construct a selector from a string at runtime, use
+/// [`Sel::register`] instead.
+///
 /// A selector with internal colons:
 ///
 /// ```
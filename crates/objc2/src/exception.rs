@@ -303,6 +303,10 @@ fn try_no_ret<F: FnOnce()>(closure: F) -> Result<(), Option<Retained<Exception>>
 ///
 /// [`catch_unwind`]: std::panic::catch_unwind
 ///
+/// This can be used to catch exceptions from individual message sends that
+/// are known to throw, without paying the cost of the `"catch-all"` feature
+/// on every message send.
+///
 ///
 /// # Errors
 ///
@@ -396,6 +400,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_catch_msg_send() {
+        let obj = AssertUnwindSafe(NSObject::new());
+        let result = catch(|| -> usize { unsafe { msg_send![&*obj, hash] } });
+        assert_eq!(result.unwrap(), obj.hash());
+    }
+
     #[test]
     fn test_throw_catch_object() {
         let obj = NSObject::new();
@@ -312,6 +312,8 @@ unsafe impl<T: ?Sized> AutoreleaseSafe for T {}
 impl !AutoreleaseSafe for Pool {}
 #[cfg(feature = "unstable-autoreleasesafe")]
 impl !AutoreleaseSafe for AutoreleasePool<'_> {}
+#[cfg(feature = "unstable-autoreleasesafe")]
+impl !AutoreleaseSafe for DrainablePool {}
 
 /// Execute `f` in the context of a new autorelease pool. The pool is drained
 /// after the execution of `f` completes.
@@ -350,6 +352,21 @@ impl !AutoreleaseSafe for AutoreleasePool<'_> {}
 /// [#540]: https://github.com/madsmtm/objc2/issues/540
 ///
 ///
+/// # Performance
+///
+/// Pushing and popping a pool is cheap, but not free, and a new pool is
+/// created on every call, even if there is already one active on the current
+/// thread. There is no way to reliably find out whether that is the case, as
+/// pools may also be created by Objective-C code (such as the main run loop).
+///
+/// In tight loops, you may want to weigh this overhead against the memory
+/// that is held on to by reusing a pool for several iterations. If you know
+/// that a pool is active, you can use [`autoreleasepool_leaking`] to skip
+/// creating a new one (at the cost of the objects staying alive until the
+/// outer pool is drained), or use [`autoreleasepool_drainable`] to drain a
+/// single pool at the points of your choosing.
+///
+///
 /// # Examples
 ///
 /// Use an external API, and ensure that the memory that it used is cleaned
@@ -455,6 +472,145 @@ where
     res
 }
 
+/// An autorelease pool that can be drained without ending its scope.
+///
+/// This is given to the closure passed to [`autoreleasepool_drainable`], see
+/// that for details.
+///
+/// This is not [`Send`] nor [`Sync`], for the same reasons as
+/// [`AutoreleasePool`].
+#[derive(Debug)]
+pub struct DrainablePool {
+    /// Always `Some`, except temporarily while being drained.
+    pool: Option<Pool>,
+}
+
+impl DrainablePool {
+    /// The current pool.
+    ///
+    /// Objects autoreleased to this pool can only be used until the next
+    /// call to [`drain`][Self::drain], which is enforced by borrowing `self`.
+    #[inline]
+    pub fn pool(&self) -> AutoreleasePool<'_> {
+        AutoreleasePool::new(self.pool.as_ref())
+    }
+
+    /// Release all objects that have been autoreleased into the pool so far.
+    ///
+    /// The pool can continue to be used afterwards.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// This must be the innermost pool, i.e. this must not be called from
+    /// inside a nested [`autoreleasepool`], or from inside any other pool
+    /// that has been pushed since, such as one created by Objective-C code
+    /// that calls back into Rust.
+    ///
+    /// Popping an outer pool also pops every pool inside it, which would
+    /// release objects that are still borrowed from the inner pools. The
+    /// nesting of pools cannot be checked reliably at runtime, since pools
+    /// created by Objective-C are not visible to Rust.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics with debug assertions enabled if this is detected to not be
+    /// the innermost pool.
+    #[doc(alias = "objc_autoreleasePoolPop")]
+    #[doc(alias = "objc_autoreleasePoolPush")]
+    #[inline]
+    pub unsafe fn drain(&mut self) {
+        self.pool().__verify_is_inner();
+        // SAFETY: We have unique access to the pool, so no references to
+        // objects in it are alive any more (as they would borrow from
+        // `self`). The caller ensures that the pool is the innermost one, so
+        // it is correct to pop it and push a new one in its place; see
+        // `autoreleasepool_drainable`.
+        if let Some(pool) = self.pool.take() {
+            unsafe { pool.drain() };
+        }
+        self.pool = Some(unsafe { Pool::new() });
+    }
+}
+
+/// Execute `f` in the context of a new autorelease pool that can be drained
+/// repeatedly.
+///
+/// This is useful for long-running loops that create many temporary
+/// objects, where calling [`autoreleasepool`] on every iteration would make
+/// it cumbersome to keep state between iterations. Instead, the loop can run
+/// inside a single closure, and call [`DrainablePool::drain`] whenever
+/// memory should be reclaimed.
+///
+/// The pool is drained a final time after `f` completes.
+///
+///
+/// # Restrictions
+///
+/// The same restrictions as for [`autoreleasepool`] apply: The pool must not
+/// be used inside a nested `autoreleasepool`. Draining it from inside a
+/// nested pool is undefined behaviour, which is why
+/// [`DrainablePool::drain`] is `unsafe`.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::rc::{autoreleasepool_drainable, Retained};
+/// use objc2::runtime::NSObject;
+///
+/// let mut count = 0;
+/// autoreleasepool_drainable(|pool| {
+///     for _ in 0..100 {
+///         // SAFETY: The pool is the current innermost pool.
+///         let obj = unsafe { Retained::autorelease(NSObject::new(), pool.pool()) };
+///         println!("{obj:?}");
+///         count += 1;
+///
+///         // Release the object.
+///         // SAFETY: No pools have been pushed since.
+///         unsafe { pool.drain() };
+///     }
+/// });
+/// assert_eq!(count, 100);
+/// ```
+///
+/// Objects in the pool cannot be used after it has been drained.
+///
+/// ```compile_fail,E0502
+/// use objc2::rc::{autoreleasepool_drainable, Retained};
+/// use objc2::runtime::NSObject;
+///
+/// autoreleasepool_drainable(|pool| {
+///     let obj = unsafe { Retained::autorelease(NSObject::new(), pool.pool()) };
+///     unsafe { pool.drain() };
+///     println!("{obj:?}");
+/// });
+/// ```
+#[doc(alias = "@autoreleasepool")]
+#[inline]
+pub fn autoreleasepool_drainable<T, F>(f: F) -> T
+where
+    F: AutoreleaseSafe + FnOnce(&mut DrainablePool) -> T,
+{
+    // SAFETY: Same as in `autoreleasepool`; the pool can only be accessed
+    // through a mutable reference inside the closure, so it can neither be
+    // moved out nor dropped out of order.
+    //
+    // `DrainablePool::drain` replaces the pool with a new one at the same
+    // level of nesting, which upholds that pools are dropped in the reverse
+    // order they were created, as long as the pool is the innermost one.
+    let mut pool = DrainablePool {
+        pool: Some(unsafe { Pool::new() }),
+    };
+    let res = f(&mut pool);
+    if let Some(pool) = pool.pool.take() {
+        unsafe { pool.drain() };
+    }
+    res
+}
+
 /// Execute `f` in the context of a "fake" autorelease pool.
 ///
 /// This is useful to create a context in which to use autoreleased objects,
@@ -541,7 +697,7 @@ mod tests {
 
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
-    use super::{autoreleasepool, AutoreleasePool, AutoreleaseSafe};
+    use super::{autoreleasepool, autoreleasepool_drainable, AutoreleasePool, AutoreleaseSafe};
     use crate::rc::{RcTestObject, Retained, ThreadTestData};
     use crate::runtime::AnyObject;
 
@@ -612,4 +768,74 @@ mod tests {
         expected.drop += 1;
         expected.assert_current();
     }
+
+    #[test]
+    fn test_drainable() {
+        let mut expected = ThreadTestData::current();
+
+        autoreleasepool_drainable(|pool| {
+            for _ in 0..1000 {
+                for _ in 0..10 {
+                    let obj = RcTestObject::new();
+                    expected.alloc += 1;
+                    expected.init += 1;
+                    let _obj = unsafe { Retained::autorelease(obj, pool.pool()) };
+                    expected.autorelease += 1;
+                }
+                expected.assert_current();
+
+                // Every object is released when the pool is drained.
+                unsafe { pool.drain() };
+                expected.release += 10;
+                expected.drop += 10;
+                expected.assert_current();
+            }
+
+            // Objects that have not been drained yet are released at the end.
+            let _obj = unsafe { Retained::autorelease(RcTestObject::new(), pool.pool()) };
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+        });
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, not(feature = "unstable-autoreleasesafe")))]
+    #[should_panic = "tried to use lifetime from pool that was not innermost"]
+    fn test_drain_in_inner_pool() {
+        autoreleasepool_drainable(|pool| {
+            autoreleasepool(|_| unsafe { pool.drain() });
+        });
+    }
+
+    #[test]
+    fn test_drain_after_inner_pool() {
+        let mut expected = ThreadTestData::current();
+        autoreleasepool_drainable(|pool| {
+            let _obj = unsafe { Retained::autorelease(RcTestObject::new(), pool.pool()) };
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+
+            // Nested pools that have ended don't prevent draining.
+            autoreleasepool(|inner| {
+                let _obj = unsafe { Retained::autorelease(RcTestObject::new(), inner) };
+            });
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+            expected.release += 1;
+            expected.drop += 1;
+            expected.assert_current();
+
+            unsafe { pool.drain() };
+            expected.release += 1;
+            expected.drop += 1;
+            expected.assert_current();
+        });
+        expected.assert_current();
+    }
 }
@@ -57,10 +57,12 @@ mod retained_traits;
 #[cfg(test)]
 mod test_object;
 mod weak;
+mod weak_array;
 
 pub use self::allocated_partial_init::{Allocated, PartialInit};
 pub use self::autorelease::{
-    autoreleasepool, autoreleasepool_leaking, AutoreleasePool, AutoreleaseSafe,
+    autoreleasepool, autoreleasepool_drainable, autoreleasepool_leaking, AutoreleasePool,
+    AutoreleaseSafe, DrainablePool,
 };
 // Re-export `Id` for backwards compatibility, but still mark it as deprecated.
 #[allow(deprecated)]
@@ -70,6 +72,7 @@ pub use self::retained_traits::{DefaultRetained, RetainedFromIterator, RetainedI
 #[cfg(test)]
 pub(crate) use self::test_object::{RcTestObject, ThreadTestData};
 pub use self::weak::Weak;
+pub use self::weak_array::WeakArray;
 // Same as above.
 #[allow(deprecated)]
 pub use self::weak::WeakId;
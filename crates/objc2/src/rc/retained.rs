@@ -76,6 +76,21 @@ use crate::{ffi, ClassType, DowncastTarget, Message};
 /// `Option<Retained<T>>` is guaranteed to have the same size as
 /// `Retained<T>`.
 ///
+/// This also means that a slice of retained objects, `&[Retained<T>]`, can be
+/// passed to C APIs that take an array of object pointers, without having to
+/// retain or release each element again; the `Vec` (or other container)
+/// releases all of the objects when it is dropped.
+///
+/// ```
+/// use objc2::rc::Retained;
+/// use objc2::runtime::NSObject;
+///
+/// let objects: Vec<Retained<NSObject>> = vec![NSObject::new(), NSObject::new()];
+/// // Pass `ptr` and `objects.len()` to a C function.
+/// let ptr: *const *mut NSObject = objects.as_ptr().cast();
+/// # let _ = ptr;
+/// ```
+///
 ///
 /// # Example
 ///
@@ -1018,6 +1033,21 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_downcast_from_msg_send() {
+        let obj: Retained<AnyObject> = unsafe { msg_send![RcTestObject::class(), new] };
+
+        // Superclasses are also valid targets.
+        let obj = obj.downcast::<NSObject>().unwrap();
+        let obj: Retained<AnyObject> = obj.into();
+        let obj = obj.downcast::<RcTestObject>().unwrap();
+        assert_eq!(obj.class(), RcTestObject::class());
+
+        let obj: Retained<AnyObject> = unsafe { msg_send![NSObject::class(), new] };
+        let obj = obj.downcast::<RcTestObject>().unwrap_err();
+        assert_eq!(obj.class(), NSObject::class());
+    }
+
     #[repr(C)]
     struct MyObject<'a> {
         inner: NSObject,
@@ -1030,6 +1060,28 @@ mod tests {
         obj
     }
 
+    #[test]
+    fn test_slice_as_pointer_array() {
+        let objects = [
+            RcTestObject::new(),
+            RcTestObject::new(),
+            RcTestObject::new(),
+        ];
+        let mut expected = ThreadTestData::current();
+
+        let ptr: *const *mut RcTestObject = objects.as_ptr().cast();
+        for (i, obj) in objects.iter().enumerate() {
+            assert_eq!(unsafe { *ptr.add(i) }, Retained::as_ptr(obj).cast_mut());
+            assert_eq!(obj.retainCount(), 1);
+        }
+        expected.assert_current();
+
+        drop(objects);
+        expected.release += 3;
+        expected.drop += 3;
+        expected.assert_current();
+    }
+
     #[test]
     fn test_size_of() {
         let ptr_size = size_of::<&NSObject>();
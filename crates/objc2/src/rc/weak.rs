@@ -7,7 +7,7 @@ use std::panic::{RefUnwindSafe, UnwindSafe};
 
 use super::Retained;
 use crate::runtime::AnyObject;
-use crate::{ffi, Message};
+use crate::{ffi, msg_send, Message};
 
 /// A weak pointer to an Objective-C reference counted object.
 ///
@@ -19,6 +19,31 @@ use crate::{ffi, Message};
 /// object has been deallocated.
 ///
 ///
+/// # Example
+///
+/// Hold a delegate weakly, such that the delegate is not kept alive by the
+/// object that uses it.
+///
+/// ```
+/// use objc2::rc::Weak;
+/// use objc2::runtime::NSObject;
+///
+/// let delegate = NSObject::new();
+/// let weak = Weak::from_retained(&delegate);
+///
+/// // Upgrade to a strong reference while the delegate is alive.
+/// let strong = weak.load().expect("delegate is alive");
+/// assert_eq!(strong, delegate);
+/// drop(strong);
+///
+/// // Once every strong reference is gone, the object is deallocated, and
+/// // loading the weak pointer will return `None`.
+/// drop(delegate);
+/// # #[cfg(not(feature = "gnustep-1-7"))]
+/// assert!(weak.load().is_none());
+/// ```
+///
+///
 /// # Comparison to `std` types
 ///
 /// This is the Objective-C equivalent of [`sync::Weak`] from the standard
@@ -109,6 +134,53 @@ impl<T: Message> Weak<T> {
         unsafe { Retained::from_raw(obj) }
     }
 
+    /// Load the object into a [`Retained`], or create a new object with the
+    /// given closure if it has been deallocated.
+    ///
+    /// Note that the weak pointer is not updated to point to the new object.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::rc::Weak;
+    /// use objc2::runtime::NSObject;
+    ///
+    /// let weak: Weak<NSObject> = Weak::default();
+    /// let obj = weak.load_or_else(NSObject::new);
+    /// ```
+    #[doc(alias = "upgrade_or_else")]
+    #[inline]
+    pub fn load_or_else(&self, f: impl FnOnce() -> Retained<T>) -> Retained<T> {
+        self.load().unwrap_or_else(f)
+    }
+
+    /// The number of strong references to the object, or `0` if it has been
+    /// deallocated.
+    ///
+    /// This is intended for diagnostics only, such as when hunting for
+    /// reference cycles. The count is read with `retainCount`, which has the
+    /// same caveats as [`NSObjectProtocol::retainCount`]; it includes
+    /// references held by autorelease pools, and some objects (such as
+    /// constant strings and tagged pointers) report a very large or otherwise
+    /// meaningless count. Additionally, the object may be retained or
+    /// released by other threads at any time.
+    ///
+    /// [`NSObjectProtocol::retainCount`]: crate::runtime::NSObjectProtocol::retainCount
+    #[doc(alias = "retainCount")]
+    pub fn strong_count(&self) -> usize {
+        match self.load() {
+            Some(obj) => {
+                // SAFETY: `retainCount` is implemented by every root class
+                // that supports weak references, and returns `NSUInteger`.
+                let count: usize = unsafe { msg_send![&*obj, retainCount] };
+                // Don't count the reference that we just loaded.
+                count.saturating_sub(1)
+            }
+            None => 0,
+        }
+    }
+
     // TODO: Add `autorelease(&self, pool) -> Option<&T>` using `objc_loadWeak`?
 }
 
@@ -259,6 +331,34 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_weak_load_repeatedly() {
+        let obj = RcTestObject::new();
+        let weak = Weak::from(&obj);
+        let mut expected = ThreadTestData::current();
+
+        for _ in 0..3 {
+            let strong = weak.load().unwrap();
+            assert!(ptr::eq(&*strong, &*obj));
+            drop(strong);
+            expected.try_retain += 1;
+            expected.release += 1;
+            expected.assert_current();
+        }
+
+        drop(obj);
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+
+        if cfg!(not(feature = "gnustep-1-7")) {
+            for _ in 0..3 {
+                assert!(weak.load().is_none());
+            }
+            expected.assert_current();
+        }
+    }
+
     #[test]
     fn test_weak_default() {
         let weak: Weak<RcTestObject> = Weak::default();
@@ -285,4 +385,58 @@ mod tests {
             mem::size_of::<*const ()>()
         );
     }
+
+    #[test]
+    fn test_weak_load_or_else() {
+        let obj = NSObject::new();
+        let weak = Weak::from(&obj);
+        let loaded = weak.load_or_else(|| unreachable!());
+        assert!(ptr::eq(&*loaded, &*obj));
+
+        let weak: Weak<NSObject> = Weak::default();
+        let new = NSObject::new();
+        let loaded = weak.load_or_else(|| new.clone());
+        assert!(ptr::eq(&*loaded, &*new));
+        assert!(weak.load().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "gnustep-1-7",
+        ignore = "weak pointers are loaded after deallocation on GNUStep"
+    )]
+    fn test_weak_strong_count_cycle() {
+        use crate::runtime::AssociationKey;
+
+        // `RcTestObject` is `Send + Sync`, so it can be associated directly.
+        static OTHER: AssociationKey<Retained<RcTestObject>> = AssociationKey::new();
+
+        let a = RcTestObject::new();
+        let b = RcTestObject::new();
+        let weak_a = Weak::from(&a);
+        let weak_b = Weak::from(&b);
+        assert_eq!(weak_a.strong_count(), 1);
+        let a2 = a.clone();
+        assert_eq!(weak_a.strong_count(), 2);
+        drop(a2);
+
+        // Create a reference cycle.
+        a.set_associated(&OTHER, b.clone());
+        b.set_associated(&OTHER, a.clone());
+        drop(a);
+        drop(b);
+
+        // The objects keep each other alive.
+        assert_eq!(weak_a.strong_count(), 1);
+        assert_eq!(weak_b.strong_count(), 1);
+
+        // Break the cycle.
+        let a = weak_a.load().unwrap();
+        a.remove_associated(&OTHER);
+        assert!(weak_b.load().is_none());
+        assert_eq!(weak_b.strong_count(), 0);
+        drop(a);
+        assert!(weak_a.load().is_none());
+        assert_eq!(weak_a.strong_count(), 0);
+    }
 }
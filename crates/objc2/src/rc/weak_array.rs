@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{Retained, Weak};
+use crate::Message;
+
+/// A list of weak pointers to Objective-C objects.
+///
+/// This is a thin wrapper around `Vec<Weak<T>>`, with helpers for the common
+/// case of only caring about the objects that are still alive, such as when
+/// keeping a list of observers or delegates that should not be kept alive
+/// by the list.
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::WeakArray;
+/// use objc2::runtime::NSObject;
+///
+/// let alive = NSObject::new();
+/// let mut observers: WeakArray<NSObject> = WeakArray::new();
+/// observers.push(&alive);
+/// observers.push(&NSObject::new()); // Deallocated immediately
+///
+/// for observer in observers.upgrade_all() {
+///     // Notify `observer`
+/// #   let _ = observer;
+/// }
+/// ```
+pub struct WeakArray<T: ?Sized> {
+    weaks: Vec<Weak<T>>,
+}
+
+impl<T: Message> WeakArray<T> {
+    /// Construct a new, empty list.
+    #[inline]
+    pub fn new() -> Self {
+        Self { weaks: Vec::new() }
+    }
+
+    /// Append a weak pointer to the given object to the list.
+    #[inline]
+    pub fn push(&mut self, obj: &T) {
+        self.weaks.push(Weak::new(obj));
+    }
+
+    /// Load all the objects that are still alive.
+    ///
+    /// The objects are returned in the order they were pushed, skipping any
+    /// that have been deallocated.
+    pub fn upgrade_all(&self) -> Vec<Retained<T>> {
+        self.weaks.iter().filter_map(Weak::load).collect()
+    }
+
+    /// Remove the weak pointers whose objects have been deallocated.
+    pub fn compact(&mut self) {
+        self.weaks.retain(|weak| weak.load().is_some());
+    }
+
+    /// The number of weak pointers in the list, including the ones whose
+    /// objects have been deallocated.
+    ///
+    /// Use [`compact`][Self::compact] first to only count live objects.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.weaks.len()
+    }
+
+    /// Whether the list contains no weak pointers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.weaks.is_empty()
+    }
+}
+
+impl<T: Message> Default for WeakArray<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for WeakArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.weaks).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr;
+
+    use super::*;
+    use crate::rc::{RcTestObject, ThreadTestData};
+
+    #[test]
+    fn test_upgrade_all() {
+        let obj1 = RcTestObject::new();
+        let obj2 = RcTestObject::new();
+        let obj3 = RcTestObject::new();
+
+        let mut weaks: WeakArray<RcTestObject> = WeakArray::new();
+        assert!(weaks.is_empty());
+        weaks.push(&obj1);
+        weaks.push(&obj2);
+        weaks.push(&obj3);
+        assert_eq!(weaks.len(), 3);
+
+        let strong = weaks.upgrade_all();
+        assert_eq!(strong.len(), 3);
+        assert!(ptr::eq(&*strong[0], &*obj1));
+        assert!(ptr::eq(&*strong[1], &*obj2));
+        assert!(ptr::eq(&*strong[2], &*obj3));
+        drop(strong);
+
+        let mut expected = ThreadTestData::current();
+        drop(obj2);
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+
+        if cfg!(not(feature = "gnustep-1-7")) {
+            // GNUStep may still load deallocated objects, see `Weak` tests.
+            let strong = weaks.upgrade_all();
+            assert_eq!(strong.len(), 2);
+            assert!(ptr::eq(&*strong[0], &*obj1));
+            assert!(ptr::eq(&*strong[1], &*obj3));
+            drop(strong);
+
+            weaks.compact();
+            assert_eq!(weaks.len(), 2);
+
+            drop(obj1);
+            drop(obj3);
+            assert!(weaks.upgrade_all().is_empty());
+            weaks.compact();
+            assert!(weaks.is_empty());
+        }
+    }
+}
@@ -7,6 +7,7 @@ use core::ptr;
 
 use super::verify_method_signature;
 use super::AnyProtocol;
+use super::Imp;
 use super::Inner;
 use super::Ivar;
 use super::Method;
@@ -188,6 +189,58 @@ impl AnyClass {
         }
     }
 
+    /// Returns the implementation of the instance method with the specified
+    /// selector, or [`None`] if instances of self do not respond to it.
+    ///
+    /// This can be used to look up the implementation once, and then call
+    /// it directly afterwards, which avoids the overhead of the dynamic
+    /// dispatch in `objc_msgSend` when sending the same message to many
+    /// objects in a hot loop.
+    ///
+    /// Unlike `class_getMethodImplementation`, this does not return the
+    /// message forwarding implementation if the method is not found.
+    ///
+    /// Same as `cls.instance_method(sel).map(Method::implementation)`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// To call the implementation, transmute it to a function pointer with
+    /// the receiver and selector as the first two arguments, followed by the
+    /// method's arguments.
+    ///
+    /// ```
+    /// use objc2::runtime::{AnyObject, NSObject, NSObjectProtocol, Sel};
+    /// use objc2::{sel, ClassType};
+    ///
+    /// let imp = NSObject::class().method_implementation(sel!(hash)).unwrap();
+    /// // SAFETY: `-[NSObject hash]` takes no arguments and returns `NSUInteger`.
+    /// let hash: unsafe extern "C-unwind" fn(&AnyObject, Sel) -> usize =
+    ///     unsafe { core::mem::transmute(imp) };
+    ///
+    /// let objects = [NSObject::new(), NSObject::new()];
+    /// for obj in &objects {
+    ///     // SAFETY: The objects are instances of exactly `NSObject`.
+    ///     let res = unsafe { hash(obj, sel!(hash)) };
+    ///     assert_eq!(res, obj.hash());
+    /// }
+    /// ```
+    ///
+    /// Calling the implementation is `unsafe`, and requires that:
+    /// - The function pointer has the exact signature of the method.
+    /// - The receiver is an instance of the class that would dispatch to this
+    ///   implementation, i.e. not an instance of a subclass that overrides
+    ///   the method.
+    /// - The implementation has not been replaced in the meantime, e.g. by
+    ///   [`Method::set_implementation`] or [`swizzle_methods`], if the
+    ///   replacement is required for correctness.
+    ///
+    /// [`swizzle_methods`]: Self::swizzle_methods
+    #[inline]
+    pub fn method_implementation(&self, sel: Sel) -> Option<Imp> {
+        self.instance_method(sel).map(Method::implementation)
+    }
+
     /// Returns a specified class method for self, or [`None`] if self and
     /// its superclasses do not contain a class method with the specified
     /// selector.
@@ -283,12 +336,70 @@ impl AnyClass {
         unsafe { ffi::class_respondsToSelector(self, sel).as_bool() }
     }
 
+    /// Exchange the implementations of two instance methods on this class.
+    ///
+    /// This is commonly known as "method swizzling", and can be used to
+    /// intercept calls to existing methods, e.g. in tests. Calling this again
+    /// with the same arguments restores the original implementations.
+    ///
+    /// If either method is inherited from a superclass, it is first added
+    /// directly to this class with the inherited implementation, such that
+    /// the superclass (and any sibling classes) are unaffected. Use
+    /// [`metaclass`][Self::metaclass] to swizzle class methods.
+    ///
+    /// See also [`Method::exchange_implementation`].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if instances of the class do not have a method for either of
+    /// the selectors.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The two methods must be compatible, see
+    /// [`Method::exchange_implementation`].
+    ///
+    /// Furthermore, swizzling is a global change that affects every caller of
+    /// these methods, including system frameworks, on all threads. Beware
+    /// that code which has already looked up the implementation of one of
+    /// these methods may keep calling the old implementation, and that
+    /// libraries you don't control may rely on the original behaviour.
+    #[doc(alias = "method_exchangeImplementations")]
+    pub unsafe fn swizzle_methods(&self, original: Sel, replacement: Sel) {
+        let cls: *mut Self = (self as *const Self).cast_mut();
+        for sel in [original, replacement] {
+            let method = self
+                .instance_method(sel)
+                .unwrap_or_else(|| panic!("method {sel} not found on class {self}"));
+            // Copy the method to this class in case it was inherited. This
+            // does nothing if the class itself already implements it.
+            //
+            // SAFETY: The implementation and types are taken from the
+            // existing method with this selector.
+            unsafe {
+                ffi::class_addMethod(
+                    cls,
+                    sel,
+                    method.implementation(),
+                    ffi::method_getTypeEncoding(method),
+                )
+            };
+        }
+
+        // The methods are now defined on this class.
+        let original = self.instance_method(original).unwrap();
+        let replacement = self.instance_method(replacement).unwrap();
+        // SAFETY: Upheld by the caller.
+        unsafe { original.exchange_implementation(replacement) };
+    }
+
     // <https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ObjCRuntimeGuide/Articles/ocrtPropertyIntrospection.html>
     // fn property(&self, name: &CStr) -> Option<&Property>;
     // fn properties(&self) -> MallocSlice!(&Property);
     // unsafe fn replace_method(&self, name: Sel, imp: Imp, types: &CStr) -> Imp;
     // unsafe fn replace_property(&self, name: &CStr, attributes: &[ffi::objc_property_attribute_t]);
-    // fn method_imp(&self, name: Sel) -> Imp; // + _stret
 
     // fn get_version(&self) -> u32;
     // unsafe fn set_version(&mut self, version: u32);
@@ -368,8 +479,9 @@ mod tests {
     use super::super::test_utils;
     use super::*;
     use crate::encode::Encode;
+    use crate::rc::Retained;
     use crate::runtime::{ClassBuilder, NSObject, NSObjectProtocol};
-    use crate::{msg_send, sel, ClassType, ProtocolType};
+    use crate::{define_class, msg_send, sel, ClassType, ProtocolType};
     use alloc::ffi::CString;
 
     // TODO: Remove once c"" strings are in MSRV
@@ -521,6 +633,83 @@ mod tests {
         assert_eq!(cls.instance_variable(&c("unknown")), None);
     }
 
+    #[test]
+    fn test_method_implementation() {
+        let cls = NSObject::class();
+        let imp = cls.method_implementation(sel!(hash)).unwrap();
+        let expected = cls.instance_method(sel!(hash)).unwrap().implementation();
+        assert_eq!(imp as *const (), expected as *const ());
+
+        let hash: unsafe extern "C-unwind" fn(&AnyObject, Sel) -> usize =
+            unsafe { core::mem::transmute(imp) };
+        for _ in 0..3 {
+            let obj = NSObject::new();
+            assert_eq!(unsafe { hash(&obj, sel!(hash)) }, obj.hash());
+        }
+
+        assert!(cls.method_implementation(sel!(unknownMethod)).is_none());
+
+        // Class methods are looked up on the metaclass.
+        let cls = test_utils::custom_class();
+        assert!(cls.method_implementation(sel!(classFoo)).is_none());
+        let imp = cls
+            .metaclass()
+            .method_implementation(sel!(classFoo))
+            .unwrap();
+        let expected = cls.class_method(sel!(classFoo)).unwrap().implementation();
+        assert_eq!(imp as *const (), expected as *const ());
+    }
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        struct SwizzleBase;
+
+        impl SwizzleBase {
+            #[unsafe(method(original))]
+            fn original(&self) -> u32 {
+                1
+            }
+
+            #[unsafe(method(replacement))]
+            fn replacement(&self) -> u32 {
+                2
+            }
+        }
+    );
+
+    define_class!(
+        #[unsafe(super(SwizzleBase, NSObject))]
+        struct SwizzleSubclass;
+    );
+
+    #[test]
+    fn test_swizzle_methods() {
+        let base: Retained<AnyObject> = unsafe { msg_send![SwizzleBase::class(), new] };
+        let sub: Retained<AnyObject> = unsafe { msg_send![SwizzleSubclass::class(), new] };
+        let original = |obj: &AnyObject| -> u32 { unsafe { msg_send![obj, original] } };
+        let replacement = |obj: &AnyObject| -> u32 { unsafe { msg_send![obj, replacement] } };
+
+        // Swizzle inherited methods on the subclass.
+        let cls = SwizzleSubclass::class();
+        unsafe { cls.swizzle_methods(sel!(original), sel!(replacement)) };
+        assert_eq!(original(&sub), 2);
+        assert_eq!(replacement(&sub), 1);
+        // The superclass is unaffected.
+        assert_eq!(original(&base), 1);
+        assert_eq!(replacement(&base), 2);
+
+        // Swizzling again restores the original behaviour.
+        unsafe { cls.swizzle_methods(sel!(original), sel!(replacement)) };
+        assert_eq!(original(&sub), 1);
+        assert_eq!(replacement(&sub), 2);
+    }
+
+    #[test]
+    #[should_panic = "method unknownMethod not found on class NSObject"]
+    fn test_swizzle_methods_unknown() {
+        unsafe { NSObject::class().swizzle_methods(sel!(hash), sel!(unknownMethod)) };
+    }
+
     #[test]
     fn test_no_ivars() {
         let cls = ClassBuilder::new(&c("NoIvarObject"), NSObject::class())
@@ -5,11 +5,13 @@ use core::ptr::NonNull;
 use super::AnyClass;
 use super::Bool;
 use super::Ivar;
+use super::ProtocolObject;
 use crate::encode::{Encode, Encoding, RefEncode};
 use crate::ffi;
 use crate::msg_send;
 use crate::DowncastTarget;
 use crate::Message;
+use crate::ProtocolType;
 
 /// An Objective-C object.
 ///
@@ -354,8 +356,53 @@ impl AnyObject {
         }
     }
 
-    // objc_setAssociatedObject
-    // objc_getAssociatedObject
+    /// Attempt to view the object as an object conforming to the protocol
+    /// `P`.
+    ///
+    /// This checks whether the object's class, or any of its superclasses,
+    /// conforms to the protocol, and returns a [`ProtocolObject`] that can
+    /// be used to call the protocol's methods if it does.
+    ///
+    /// Returns `None` if the object does not conform to the protocol, or if
+    /// the protocol is not registered with the runtime (see
+    /// [`ProtocolType::protocol`]).
+    ///
+    /// This is useful when receiving e.g. a delegate object as an untyped
+    /// object. See [`ProtocolObject::from_ref`] if the conformance is known
+    /// statically.
+    ///
+    /// [`ProtocolObject`]: crate::runtime::ProtocolObject
+    /// [`ProtocolObject::from_ref`]: crate::runtime::ProtocolObject::from_ref
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+    ///
+    /// let obj = NSObject::new();
+    /// let proto: &ProtocolObject<dyn NSObjectProtocol> = obj.as_protocol().unwrap();
+    /// assert_eq!(proto.hash(), obj.hash());
+    /// ```
+    #[doc(alias = "conformsToProtocol:")]
+    pub fn as_protocol<P: ?Sized + ProtocolType>(&self) -> Option<&ProtocolObject<P>> {
+        let protocol = P::protocol()?;
+        let mut cls = Some(self.class());
+        while let Some(current) = cls {
+            if current.conforms_to(protocol) {
+                // SAFETY: Just checked that the object conforms to the
+                // protocol.
+                //
+                // `P: ProtocolType` is only implemented for the bare
+                // `dyn Protocol`, so this does not allow adding auto traits
+                // like `Send` or `Sync`.
+                return Some(unsafe { &*(self as *const Self).cast::<ProtocolObject<P>>() });
+            }
+            cls = current.superclass();
+        }
+        None
+    }
+
     // objc_removeAssociatedObjects
 }
 
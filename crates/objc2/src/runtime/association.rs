@@ -0,0 +1,281 @@
+use alloc::sync::Arc;
+use core::any::Any;
+use core::ffi::c_void;
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::{AnyObject, NSObject};
+use crate::rc::{autoreleasepool, Retained};
+use crate::{define_class, ffi, msg_send, AnyThread, DefinedClass};
+
+/// A key used to associate a value of type `T` with an Objective-C object.
+///
+/// The address of the key is what identifies the association, so keys must
+/// be stored in a `static`.
+///
+/// See [`AnyObject::set_associated`] for details.
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::runtime::{AssociationKey, NSObject};
+///
+/// static NAME: AssociationKey<String> = AssociationKey::new();
+///
+/// let obj = NSObject::new();
+/// obj.set_associated(&NAME, "foo".to_string());
+/// assert_eq!(*obj.get_associated(&NAME).unwrap(), "foo");
+/// ```
+#[doc(alias = "objc_setAssociatedObject")]
+#[doc(alias = "objc_getAssociatedObject")]
+pub struct AssociationKey<T: ?Sized> {
+    /// Make the key non-zero-sized, to guarantee that each key has a unique
+    /// address.
+    _unique: u8,
+    /// `fn() -> T` to make the key `Send + Sync` regardless of `T`.
+    p: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized> AssociationKey<T> {
+    /// Create a new association key.
+    #[inline]
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            _unique: 0,
+            p: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_ptr(&'static self) -> *const c_void {
+        let ptr: *const Self = self;
+        ptr.cast()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for AssociationKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ptr: *const Self = self;
+        f.debug_tuple("AssociationKey").field(&ptr).finish()
+    }
+}
+
+define_class!(
+    /// The object that Rust values are stored in when associated with an
+    /// object, such that they are dropped when the association is released.
+    #[unsafe(super(NSObject))]
+    #[ivars = Arc<dyn Any + Send + Sync>]
+    pub(crate) struct AssociatedValue;
+);
+
+impl AssociatedValue {
+    pub(crate) fn new(value: Arc<dyn Any + Send + Sync>) -> Retained<Self> {
+        let this = Self::alloc().set_ivars(value);
+        // SAFETY: `NSObject`'s `init` method is safe to call.
+        unsafe { msg_send![super(this), init] }
+    }
+}
+
+/// Associated objects.
+impl AnyObject {
+    /// Associate a value with this object.
+    ///
+    /// The value is kept alive until it is replaced by another call to this
+    /// method with the same key, removed with
+    /// [`remove_associated`][Self::remove_associated], or until the object is
+    /// deallocated. It is then dropped (unless it has been cloned out with
+    /// [`get_associated`][Self::get_associated]).
+    ///
+    /// The value is stored with the `OBJC_ASSOCIATION_RETAIN` policy. To use
+    /// other policies, or to associate Objective-C objects without wrapping
+    /// them, use [`ffi::objc_setAssociatedObject`] directly.
+    ///
+    /// Note that the Objective-C runtime aborts the process if the object's
+    /// class does not support associated objects.
+    ///
+    ///
+    /// # Thread safety
+    ///
+    /// The value must be `Send + Sync`, since the object may be shared with
+    /// other threads, which can then retrieve the value, and the value is
+    /// dropped on whichever thread deallocates the object.
+    ///
+    /// This includes `Retained<T>` when `T` is `Send + Sync`, which is the
+    /// case for many Foundation classes, and for classes created with
+    /// [`define_class!`] that inherit from `NSObject` (and that aren't
+    /// main-thread-only). Other objects, such as `Retained<NSObject>`, must
+    /// instead be associated with [`ffi::objc_setAssociatedObject`].
+    ///
+    /// [`define_class!`]: crate::define_class
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::{AssociationKey, NSObject};
+    ///
+    /// static COUNT: AssociationKey<u32> = AssociationKey::new();
+    ///
+    /// let obj = NSObject::new();
+    /// assert!(obj.get_associated(&COUNT).is_none());
+    ///
+    /// obj.set_associated(&COUNT, 1);
+    /// assert_eq!(*obj.get_associated(&COUNT).unwrap(), 1);
+    ///
+    /// obj.set_associated(&COUNT, 2);
+    /// assert_eq!(*obj.get_associated(&COUNT).unwrap(), 2);
+    ///
+    /// obj.remove_associated(&COUNT);
+    /// assert!(obj.get_associated(&COUNT).is_none());
+    /// ```
+    #[doc(alias = "objc_setAssociatedObject")]
+    pub fn set_associated<T: Send + Sync + 'static>(
+        &self,
+        key: &'static AssociationKey<T>,
+        value: T,
+    ) {
+        let value = AssociatedValue::new(Arc::new(value));
+        let value: *const AssociatedValue = Retained::as_ptr(&value);
+        // SAFETY: The value is a valid object, and the policy is valid.
+        //
+        // The runtime retains the value, so it is fine that we release our
+        // reference to it at the end of this function.
+        unsafe { self.set_associated_raw(key.as_ptr(), value as *mut AnyObject) };
+    }
+
+    /// Get the value associated with this object under the given key.
+    ///
+    /// Returns [`None`] if no value is associated with the key.
+    ///
+    /// See [`set_associated`][Self::set_associated] for details.
+    #[doc(alias = "objc_getAssociatedObject")]
+    pub fn get_associated<T: Send + Sync + 'static>(
+        &self,
+        key: &'static AssociationKey<T>,
+    ) -> Option<Arc<T>> {
+        // The getter retains and autoreleases the value when using the
+        // `OBJC_ASSOCIATION_RETAIN` policy, so make sure that we don't leak
+        // it into an outer pool.
+        autoreleasepool(|_| {
+            // SAFETY: The object is valid.
+            let value = unsafe { ffi::objc_getAssociatedObject(self, key.as_ptr()) };
+            // SAFETY: The value is either NULL or a valid object, which has
+            // been retained and autoreleased into the current pool.
+            let value = unsafe { Retained::retain(value as *mut AnyObject) }?;
+            // Only `set_associated` uses the key, so the value is always an
+            // `AssociatedValue` containing a `T`. Check it anyhow, in case
+            // the key was used in a call to `objc_setAssociatedObject`.
+            let value = value.downcast_ref::<AssociatedValue>()?;
+            value.ivars().clone().downcast::<T>().ok()
+        })
+    }
+
+    /// Remove the value associated with this object under the given key.
+    ///
+    /// The value is dropped, unless it has been cloned out with
+    /// [`get_associated`][Self::get_associated].
+    #[doc(alias = "objc_setAssociatedObject")]
+    pub fn remove_associated<T: Send + Sync + 'static>(&self, key: &'static AssociationKey<T>) {
+        // SAFETY: Setting the value to NULL removes the association.
+        unsafe { self.set_associated_raw(key.as_ptr(), ffi::nil) };
+    }
+
+    /// # Safety
+    ///
+    /// The value must be a valid object or NULL.
+    unsafe fn set_associated_raw(&self, key: *const c_void, value: *mut AnyObject) {
+        let obj: *const Self = self;
+        // SAFETY: The object is valid, and the caller upholds that the value
+        // is valid. The runtime synchronizes access to associated objects.
+        unsafe {
+            ffi::objc_setAssociatedObject(
+                obj as *mut Self,
+                key,
+                value,
+                ffi::OBJC_ASSOCIATION_RETAIN,
+            )
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::rc::RcTestObject;
+
+    struct DropCounter(&'static AtomicUsize);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_set_get_remove() {
+        static KEY: AssociationKey<u32> = AssociationKey::new();
+        static OTHER: AssociationKey<u32> = AssociationKey::new();
+
+        let obj = NSObject::new();
+        assert_eq!(obj.get_associated(&KEY), None);
+
+        obj.set_associated(&KEY, 42);
+        assert_eq!(obj.get_associated(&KEY).as_deref(), Some(&42));
+        assert_eq!(obj.get_associated(&OTHER), None);
+
+        // Associations are per-object.
+        let obj2 = NSObject::new();
+        assert_eq!(obj2.get_associated(&KEY), None);
+
+        obj.set_associated(&KEY, 43);
+        assert_eq!(obj.get_associated(&KEY).as_deref(), Some(&43));
+
+        obj.remove_associated(&KEY);
+        assert_eq!(obj.get_associated(&KEY), None);
+        obj.remove_associated(&KEY);
+    }
+
+    #[test]
+    fn test_replace_and_remove_drops() {
+        static KEY: AssociationKey<DropCounter> = AssociationKey::new();
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        let obj = NSObject::new();
+        obj.set_associated(&KEY, DropCounter(&DROPPED));
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+
+        obj.set_associated(&KEY, DropCounter(&DROPPED));
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+        // Values that have been cloned out stay alive.
+        let value = obj.get_associated(&KEY).unwrap();
+        obj.remove_associated(&KEY);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+        drop(value);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_dropped_on_dealloc() {
+        static KEY: AssociationKey<DropCounter> = AssociationKey::new();
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        let obj = RcTestObject::new();
+        obj.set_associated(&KEY, DropCounter(&DROPPED));
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+
+        drop(obj);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_key_is_not_zero_sized() {
+        static KEY1: AssociationKey<()> = AssociationKey::new();
+        static KEY2: AssociationKey<()> = AssociationKey::new();
+        assert_ne!(KEY1.as_ptr(), KEY2.as_ptr());
+    }
+}
@@ -805,6 +805,31 @@ mod tests {
         let _builder = ClassBuilder::new(&c("TestClassBuilderDrop"), cls).unwrap();
     }
 
+    #[test]
+    fn test_classbuilder_other_thread() {
+        extern "C-unwind" fn get_value(_this: &NSObject, _cmd: Sel) -> u32 {
+            42
+        }
+
+        // Allocate the class on one thread, and add methods to and register
+        // it on another.
+        let builder =
+            ClassBuilder::new(&c("TestClassBuilderOtherThread"), NSObject::class()).unwrap();
+        let cls = std::thread::spawn(move || {
+            let mut builder = builder;
+            unsafe {
+                builder.add_method(sel!(getValue), get_value as extern "C-unwind" fn(_, _) -> _);
+            }
+            builder.register()
+        })
+        .join()
+        .unwrap();
+
+        let obj: Retained<NSObject> = unsafe { msg_send![cls, new] };
+        let value: u32 = unsafe { msg_send![&obj, getValue] };
+        assert_eq!(value, 42);
+    }
+
     #[test]
     fn test_custom_class() {
         // Registering the custom class is in test_utils
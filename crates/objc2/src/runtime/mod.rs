@@ -60,6 +60,8 @@ pub mod __nsstring;
 mod anyclass;
 mod anyobject;
 mod anyprotocol;
+#[cfg(not(feature = "unstable-objfw"))]
+mod association;
 mod bool;
 mod define;
 mod ivar;
@@ -74,6 +76,7 @@ mod nszone;
 mod protocol_object;
 mod retain_release_fast;
 mod sel;
+mod swizzle;
 #[cfg(test)]
 pub(crate) mod test_utils;
 mod verify;
@@ -92,6 +95,8 @@ pub use self::anyobject::Object;
 pub use self::anyprotocol::AnyProtocol;
 #[allow(deprecated)]
 pub use self::anyprotocol::Protocol;
+#[cfg(not(feature = "unstable-objfw"))]
+pub use self::association::AssociationKey;
 pub use self::bool::Bool;
 pub use self::define::{ClassBuilder, ProtocolBuilder};
 pub use self::ivar::Ivar;
@@ -102,6 +107,7 @@ pub use self::nsobject::{NSObject, NSObjectProtocol};
 pub use self::nszone::NSZone;
 pub use self::protocol_object::{ImplementedBy, ProtocolObject};
 pub use self::sel::Sel;
+pub use self::swizzle::{Swizzle, SwizzleError};
 pub use self::verify::VerificationError;
 
 #[allow(deprecated)]
@@ -442,6 +442,10 @@ impl NSObject {
 /// At the very least, equality is _expected_ to be symmetric and
 /// transitive, and that's about the best we can do.
 ///
+/// This only compares objects of the same type. Objective-C equality is
+/// heterogeneous though, use [`NSObjectProtocol::isEqual`] to compare
+/// against an object of any type.
+///
 /// See also <https://nshipster.com/equality/>
 impl PartialEq for NSObject {
     #[inline]
@@ -3,7 +3,7 @@
 use core::fmt;
 use core::hash;
 
-use crate::runtime::{AnyClass, AnyObject, NSObjectProtocol, ProtocolObject};
+use crate::runtime::{AnyClass, AnyObject, NSObjectProtocol};
 use crate::{extern_conformance, AnyThread, ClassType, DowncastTarget};
 
 /// An abstract superclass defining an API for objects that act as
@@ -70,11 +70,79 @@ impl hash::Hash for NSProxy {
 }
 
 impl fmt::Debug for NSProxy {
-    #[inline]
-    #[doc(alias = "description")]
-    #[doc(alias = "debugDescription")]
+    /// Formats the proxy's class and address, e.g. `<MyProxy: 0x1234>`.
+    ///
+    /// Unlike for `NSObject`, this does not use `description`, since
+    /// proxies usually forward that to the object they stand in for, which
+    /// may be expensive or fail (e.g. for distributed objects, it could
+    /// involve remote messaging). Use [`NSObjectProtocol::description`]
+    /// explicitly if you want that.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let obj: &ProtocolObject<dyn NSObjectProtocol> = ProtocolObject::from_ref(self);
-        obj.fmt(f)
+        // `AnyObject::class` uses the runtime, and does not message the
+        // object.
+        write!(f, "<{}: {:p}>", AnyObject::class(self), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use core::ptr;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::rc::{Allocated, Retained};
+    use crate::runtime::{NSObject, Sel};
+    use crate::{define_class, msg_send, ClassType};
+
+    static MESSAGES: AtomicUsize = AtomicUsize::new(0);
+
+    define_class!(
+        /// A proxy that counts the messages that would usually be forwarded.
+        #[unsafe(super(NSProxy))]
+        #[name = "__NSProxyDebugTest"]
+        struct CountingProxy;
+
+        impl CountingProxy {
+            #[unsafe(method(description))]
+            fn description(&self) -> *mut AnyObject {
+                MESSAGES.fetch_add(1, Ordering::Relaxed);
+                ptr::null_mut()
+            }
+
+            #[unsafe(method(debugDescription))]
+            fn debug_description(&self) -> *mut AnyObject {
+                MESSAGES.fetch_add(1, Ordering::Relaxed);
+                ptr::null_mut()
+            }
+
+            #[unsafe(method(methodSignatureForSelector:))]
+            fn method_signature_for_selector(&self, _sel: Sel) -> *mut AnyObject {
+                MESSAGES.fetch_add(1, Ordering::Relaxed);
+                ptr::null_mut()
+            }
+
+            #[unsafe(method(forwardInvocation:))]
+            fn forward_invocation(&self, _invocation: *mut AnyObject) {
+                MESSAGES.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    );
+
+    #[test]
+    fn test_debug_does_not_message() {
+        let obj: Allocated<CountingProxy> = unsafe { msg_send![CountingProxy::class(), alloc] };
+        // SAFETY: `NSProxy` does not have an initializer.
+        let obj: Retained<CountingProxy> = unsafe { Retained::from_raw(Allocated::into_ptr(obj)) }
+            .expect("failed allocating proxy");
+        let proxy: &NSProxy = &obj;
+        assert!(proxy.isProxy());
+
+        let expected = format!("<__NSProxyDebugTest: {:p}>", proxy);
+        assert_eq!(format!("{proxy:?}"), expected);
+        assert_eq!(format!("{proxy:#?}"), expected);
+        assert_eq!(MESSAGES.load(Ordering::Relaxed), 0);
+
+        assert!(!NSObject::new().isProxy());
     }
 }
@@ -207,10 +207,10 @@ mod tests {
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
     use super::*;
-    use crate::runtime::{ClassBuilder, NSObject};
+    use crate::runtime::{ClassBuilder, NSObject, ProtocolBuilder};
     use crate::{
         define_class, extern_class, extern_conformance, extern_methods, extern_protocol, msg_send,
-        ClassType,
+        sel, ClassType, ProtocolType,
     };
 
     extern_protocol!(
@@ -394,6 +394,66 @@ mod tests {
         assert_eq!(hashstate_a.finish(), hashstate_b.finish());
     }
 
+    extern_protocol!(
+        unsafe trait AsProtocolDelegate: NSObjectProtocol {
+            #[unsafe(method(delegateValue))]
+            fn delegate_value(&self) -> i32;
+        }
+    );
+
+    fn register_delegate_protocol() {
+        static REGISTER: std::sync::Once = std::sync::Once::new();
+        REGISTER.call_once(|| {
+            let name = CStr::from_bytes_with_nul(b"AsProtocolDelegate\0").unwrap();
+            let mut builder = ProtocolBuilder::new(name).unwrap();
+            builder.add_protocol(<dyn NSObjectProtocol>::protocol().unwrap());
+            builder.add_method_description::<(), i32>(sel!(delegateValue), true);
+            builder.register();
+        });
+    }
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        struct DelegateClass;
+
+        unsafe impl NSObjectProtocol for DelegateClass {}
+
+        unsafe impl AsProtocolDelegate for DelegateClass {
+            #[unsafe(method(delegateValue))]
+            fn _delegate_value(&self) -> i32 {
+                42
+            }
+        }
+    );
+
+    define_class!(
+        #[unsafe(super(DelegateClass, NSObject))]
+        struct DelegateSubclass;
+    );
+
+    #[test]
+    fn as_protocol() {
+        // The protocol must be registered before the class.
+        register_delegate_protocol();
+
+        let obj: Retained<AnyObject> = unsafe { msg_send![DelegateClass::class(), new] };
+        let delegate = obj.as_protocol::<dyn AsProtocolDelegate>().unwrap();
+        assert_eq!(delegate.delegate_value(), 42);
+        let _: &ProtocolObject<dyn NSObjectProtocol> = obj.as_protocol().unwrap();
+
+        // Conformance is inherited from the superclass.
+        let obj: Retained<AnyObject> = unsafe { msg_send![DelegateSubclass::class(), new] };
+        let delegate = obj.as_protocol::<dyn AsProtocolDelegate>().unwrap();
+        assert_eq!(delegate.delegate_value(), 42);
+
+        let obj = NSObject::new();
+        assert!(obj.as_protocol::<dyn AsProtocolDelegate>().is_none());
+
+        // `Foo` is not registered with the runtime.
+        let obj = DummyClass::new();
+        assert!(obj.as_protocol::<dyn Foo>().is_none());
+    }
+
     // We use `debug_assertions` here just because it's something that we know
     // our CI already tests.
     extern_protocol!(
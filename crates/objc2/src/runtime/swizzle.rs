@@ -0,0 +1,241 @@
+use core::fmt;
+use core::mem;
+use std::error::Error;
+
+use super::{AnyClass, Method, Sel};
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum Inner {
+    MethodNotFound(Sel),
+    MismatchedTypes(Sel, Sel),
+}
+
+impl fmt::Display for Inner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MethodNotFound(sel) => write!(f, "method {sel} not found"),
+            Self::MismatchedTypes(original, replacement) => {
+                write!(
+                    f,
+                    "methods {original} and {replacement} have different types"
+                )
+            }
+        }
+    }
+}
+
+/// Failed swizzling methods on a class.
+///
+/// This is returned in the error case of [`AnyClass::swizzle_methods_scoped`],
+/// see that for details.
+///
+/// This implements [`Error`], and a description of the error can be retrieved
+/// using [`fmt::Display`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SwizzleError(Inner);
+
+impl fmt::Display for SwizzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Delegate to inner
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for SwizzleError {}
+
+/// A guard that restores swizzled methods when dropped.
+///
+/// This is returned from [`AnyClass::swizzle_methods_scoped`], see that for
+/// details.
+#[derive(Debug)]
+#[must_use = "if unused, the methods are immediately swizzled back"]
+pub struct Swizzle {
+    cls: &'static AnyClass,
+    original: Sel,
+    replacement: Sel,
+}
+
+impl Swizzle {
+    /// Keep the methods swizzled, instead of restoring them when the guard
+    /// is dropped.
+    #[inline]
+    pub fn forget(self) {
+        mem::forget(self);
+    }
+}
+
+impl Drop for Swizzle {
+    fn drop(&mut self) {
+        // SAFETY: The methods were compatible when they were swizzled, and
+        // exchanging them again restores the original implementations.
+        unsafe { self.cls.swizzle_methods(self.original, self.replacement) };
+    }
+}
+
+fn same_types(a: &Method, b: &Method) -> bool {
+    a.arguments_count() == b.arguments_count()
+        && *a.return_type() == *b.return_type()
+        && (0..a.arguments_count())
+            .all(|i| a.argument_type(i).as_deref() == b.argument_type(i).as_deref())
+}
+
+/// Swizzling.
+impl AnyClass {
+    /// Exchange the implementations of two instance methods on this class
+    /// until the returned guard is dropped.
+    ///
+    /// This is similar to [`swizzle_methods`][Self::swizzle_methods], but the
+    /// returned guard swizzles the methods back when dropped. If the same
+    /// methods are swizzled multiple times, the guards must be dropped in the
+    /// reverse order of creation to restore the original implementations.
+    /// Use [`Swizzle::forget`] to keep the methods swizzled.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns an error instead of panicking if instances of the class do not
+    /// have a method for either of the selectors, or if the type encodings of
+    /// the two methods differ. Nothing is swizzled in that case.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`swizzle_methods`][Self::swizzle_methods]. The type encodings
+    /// are checked, but they do not capture everything about a method's
+    /// signature (e.g. they don't distinguish between different object
+    /// types), so the caller must still ensure that the methods are
+    /// compatible.
+    ///
+    /// Additionally, the methods must still be compatible when the guard is
+    /// dropped.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::NSObject;
+    /// use objc2::{msg_send, sel, ClassType};
+    ///
+    /// let cls = NSObject::class();
+    /// let obj = NSObject::new();
+    /// let hash: usize = unsafe { msg_send![&obj, hash] };
+    ///
+    /// // Swap `hash` with another method returning `NSUInteger`.
+    /// let guard = unsafe { cls.swizzle_methods_scoped(sel!(hash), sel!(retainCount)) }.unwrap();
+    /// let swizzled: usize = unsafe { msg_send![&obj, hash] };
+    /// assert_ne!(swizzled, hash);
+    ///
+    /// drop(guard);
+    /// let restored: usize = unsafe { msg_send![&obj, hash] };
+    /// assert_eq!(restored, hash);
+    ///
+    /// // Methods with different types cannot be swizzled.
+    /// assert!(unsafe { cls.swizzle_methods_scoped(sel!(hash), sel!(description)) }.is_err());
+    /// ```
+    #[doc(alias = "method_exchangeImplementations")]
+    pub unsafe fn swizzle_methods_scoped(
+        &'static self,
+        original: Sel,
+        replacement: Sel,
+    ) -> Result<Swizzle, SwizzleError> {
+        let method = |sel| {
+            self.instance_method(sel)
+                .ok_or(SwizzleError(Inner::MethodNotFound(sel)))
+        };
+        if !same_types(method(original)?, method(replacement)?) {
+            return Err(SwizzleError(Inner::MismatchedTypes(original, replacement)));
+        }
+
+        // SAFETY: Upheld by the caller.
+        unsafe { self.swizzle_methods(original, replacement) };
+        Ok(Swizzle {
+            cls: self,
+            original,
+            replacement,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::rc::Retained;
+    use crate::runtime::{AnyObject, NSObject};
+    use crate::{define_class, msg_send, sel, ClassType};
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        struct SwizzleScoped;
+
+        impl SwizzleScoped {
+            #[unsafe(method(original))]
+            fn original(&self) -> u32 {
+                1
+            }
+
+            #[unsafe(method(replacement))]
+            fn replacement(&self) -> u32 {
+                2
+            }
+
+            #[unsafe(method(other))]
+            fn other(&self) -> bool {
+                false
+            }
+        }
+    );
+
+    fn original(obj: &AnyObject) -> u32 {
+        unsafe { msg_send![obj, original] }
+    }
+
+    #[test]
+    fn test_drop_restores() {
+        let obj: Retained<AnyObject> = unsafe { msg_send![SwizzleScoped::class(), new] };
+        let cls = SwizzleScoped::class();
+
+        let guard =
+            unsafe { cls.swizzle_methods_scoped(sel!(original), sel!(replacement)) }.unwrap();
+        assert_eq!(original(&obj), 2);
+        drop(guard);
+        assert_eq!(original(&obj), 1);
+
+        // Nested guards.
+        let guard1 =
+            unsafe { cls.swizzle_methods_scoped(sel!(original), sel!(replacement)) }.unwrap();
+        let guard2 =
+            unsafe { cls.swizzle_methods_scoped(sel!(original), sel!(replacement)) }.unwrap();
+        assert_eq!(original(&obj), 1);
+        drop(guard2);
+        assert_eq!(original(&obj), 2);
+        drop(guard1);
+        assert_eq!(original(&obj), 1);
+
+        // Forgetting keeps the methods swizzled.
+        unsafe { cls.swizzle_methods_scoped(sel!(original), sel!(replacement)) }
+            .unwrap()
+            .forget();
+        assert_eq!(original(&obj), 2);
+        unsafe { cls.swizzle_methods(sel!(original), sel!(replacement)) };
+        assert_eq!(original(&obj), 1);
+    }
+
+    #[test]
+    fn test_errors() {
+        let cls = SwizzleScoped::class();
+
+        let err = unsafe { cls.swizzle_methods_scoped(sel!(original), sel!(unknown)) }.unwrap_err();
+        assert_eq!(err.to_string(), "method unknown not found");
+
+        let err = unsafe { cls.swizzle_methods_scoped(sel!(original), sel!(other)) }.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "methods original and other have different types"
+        );
+
+        // Nothing was swizzled.
+        let obj: Retained<AnyObject> = unsafe { msg_send![cls, new] };
+        assert_eq!(original(&obj), 1);
+    }
+}
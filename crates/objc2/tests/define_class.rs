@@ -615,3 +615,134 @@ fn name_can_be_expr() {
     assert_eq!(Name5Concat::class().name().to_str().unwrap(), expected);
     assert_eq!(Name5Concat::NAME, expected);
 }
+
+#[test]
+fn delegate_with_ivars() {
+    use std::cell::Cell;
+
+    use objc2::runtime::{NSObjectProtocol, ProtocolObject};
+    use objc2::{msg_send, AnyThread, DefinedClass, ProtocolType};
+
+    struct Ivars {
+        calls: Cell<u32>,
+    }
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[ivars = Ivars]
+        struct Delegate;
+
+        impl Delegate {
+            #[unsafe(method(didCall))]
+            fn did_call(&self) {
+                self.ivars().calls.set(self.ivars().calls.get() + 1);
+            }
+        }
+
+        unsafe impl NSObjectProtocol for Delegate {}
+    );
+
+    let this = Delegate::alloc().set_ivars(Ivars {
+        calls: Cell::new(0),
+    });
+    let delegate: Retained<Delegate> = unsafe { msg_send![super(this), init] };
+
+    // Called from "Objective-C" through a protocol object.
+    let proto: &ProtocolObject<dyn NSObjectProtocol> = ProtocolObject::from_ref(&*delegate);
+    let _: () = unsafe { msg_send![proto, didCall] };
+    let _: () = unsafe { msg_send![proto, didCall] };
+    assert_eq!(delegate.ivars().calls.get(), 2);
+
+    // Derefs to the superclass.
+    let superclass: &NSObject = &delegate;
+    assert!(superclass.isKindOfClass(Delegate::class()));
+    assert!(Delegate::class().conforms_to(<dyn NSObjectProtocol>::protocol().unwrap()));
+}
+
+#[test]
+fn override_init_calls_super() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use objc2::rc::Allocated;
+    use objc2::runtime::NSObjectProtocol;
+    use objc2::{msg_send, AnyThread, DefinedClass};
+
+    static INIT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[ivars = u32]
+        struct OverrideInit;
+
+        impl OverrideInit {
+            #[unsafe(method_id(init))]
+            fn init(this: Allocated<Self>) -> Retained<Self> {
+                INIT_CALLS.fetch_add(1, Ordering::Relaxed);
+                let this = this.set_ivars(42);
+                // Calls `-[NSObject init]`.
+                unsafe { msg_send![super(this), init] }
+            }
+        }
+    );
+
+    // `new` calls our overridden `init`.
+    let obj: Retained<OverrideInit> = unsafe { msg_send![OverrideInit::class(), new] };
+    assert_eq!(*obj.ivars(), 42);
+    assert_eq!(INIT_CALLS.load(Ordering::Relaxed), 1);
+
+    // As does calling `init` explicitly.
+    let obj: Retained<OverrideInit> = unsafe { msg_send![OverrideInit::alloc(), init] };
+    assert_eq!(*obj.ivars(), 42);
+    assert_eq!(INIT_CALLS.load(Ordering::Relaxed), 2);
+    assert!(obj.isKindOfClass(NSObject::class()));
+}
+
+#[test]
+fn ivars_mutated_and_dropped() {
+    use std::cell::RefCell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use objc2::{msg_send, AnyThread, DefinedClass};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Ivars {
+        values: RefCell<Vec<u32>>,
+    }
+
+    impl Drop for Ivars {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[ivars = Ivars]
+        struct Collector;
+
+        impl Collector {
+            #[unsafe(method(push:))]
+            fn push(&self, value: u32) {
+                self.ivars().values.borrow_mut().push(value);
+            }
+        }
+    );
+
+    let this = Collector::alloc().set_ivars(Ivars {
+        values: RefCell::new(vec![1]),
+    });
+    let obj: Retained<Collector> = unsafe { msg_send![super(this), init] };
+    let _: () = unsafe { msg_send![&obj, push: 2u32] };
+    let _: () = unsafe { msg_send![&obj, push: 3u32] };
+    assert_eq!(*obj.ivars().values.borrow(), [1, 2, 3]);
+
+    // Dropped when the object is deallocated.
+    assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+    drop(obj);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+
+    // Not dropped if the object was never initialized.
+    drop(Collector::alloc());
+    assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+}
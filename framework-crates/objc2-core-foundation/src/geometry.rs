@@ -321,23 +321,206 @@ impl CGRect {
         // self.size.width <= 0.0 || self.size.height <= 0.0
     }
 
+    /// The null rectangle, i.e. the result of intersecting two rectangles
+    /// that do not overlap.
+    ///
+    /// This has its origin at infinity, and is different from an empty
+    /// rectangle, see [`is_null`][Self::is_null].
+    #[doc(alias = "CGRectNull")]
+    pub const NULL: Self = Self::new(
+        CGPoint::new(CGFloat::INFINITY, CGFloat::INFINITY),
+        CGSize::ZERO,
+    );
+
+    /// A rectangle that has no defined bounds.
+    ///
+    /// Intersecting this with another rectangle returns the other rectangle,
+    /// see [`is_infinite`][Self::is_infinite].
+    #[doc(alias = "CGRectInfinite")]
+    pub const INFINITE: Self = Self::new(
+        CGPoint::new(-CGFloat::MAX / 2.0, -CGFloat::MAX / 2.0),
+        CGSize::new(CGFloat::MAX, CGFloat::MAX),
+    );
+
+    /// Returns whether the rectangle is [the null rectangle][Self::NULL].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::CGRect;
+    /// assert!(CGRect::NULL.is_null());
+    /// assert!(!CGRect::ZERO.is_null());
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectIsNull")]
+    pub fn is_null(self) -> bool {
+        self.origin.x == CGFloat::INFINITY || self.origin.y == CGFloat::INFINITY
+    }
+
+    /// Returns whether the rectangle is [the infinite
+    /// rectangle][Self::INFINITE].
+    #[inline]
+    #[doc(alias = "CGRectIsInfinite")]
+    pub fn is_infinite(self) -> bool {
+        self == Self::INFINITE
+    }
+
+    /// Like [`standardize`][Self::standardize], but also moves the origin,
+    /// so that the rectangle covers the same area.
+    fn normalize(self) -> Self {
+        let (x, width) = if self.size.width < 0.0 {
+            (self.origin.x + self.size.width, -self.size.width)
+        } else {
+            (self.origin.x, self.size.width)
+        };
+        let (y, height) = if self.size.height < 0.0 {
+            (self.origin.y + self.size.height, -self.size.height)
+        } else {
+            (self.origin.y, self.size.height)
+        };
+        Self::new(CGPoint::new(x, y), CGSize::new(width, height))
+    }
+
+    /// Returns whether the point lies within the rectangle.
+    ///
+    /// Points on the minimum edges of the rectangle are contained within it,
+    /// points on the maximum edges are not. The null rectangle contains no
+    /// points.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let rect = CGRect::new(CGPoint::new(1.0, 1.0), CGSize::new(2.0, 2.0));
+    /// assert!(rect.contains_point(CGPoint::new(1.0, 2.5)));
+    /// assert!(!rect.contains_point(CGPoint::new(3.0, 2.5)));
+    /// ```
+    #[doc(alias = "CGRectContainsPoint")]
+    pub fn contains_point(self, point: CGPoint) -> bool {
+        if self.is_null() {
+            return false;
+        }
+        let rect = self.normalize();
+        let (min, max) = (rect.min(), rect.max());
+        min.x <= point.x && point.x < max.x && min.y <= point.y && point.y < max.y
+    }
+
+    /// The intersection of two rectangles.
+    ///
+    /// Returns [the null rectangle][Self::NULL] if the rectangles do not
+    /// intersect, or if either of them is null. Rectangles that only share
+    /// an edge intersect in an empty rectangle.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let a = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(2.0, 2.0));
+    /// let b = CGRect::new(CGPoint::new(1.0, 1.0), CGSize::new(2.0, 2.0));
+    /// let expected = CGRect::new(CGPoint::new(1.0, 1.0), CGSize::new(1.0, 1.0));
+    /// assert_eq!(a.intersection(b), expected);
+    ///
+    /// let c = CGRect::new(CGPoint::new(5.0, 5.0), CGSize::new(1.0, 1.0));
+    /// assert!(a.intersection(c).is_null());
+    /// ```
+    #[doc(alias = "CGRectIntersection")]
+    pub fn intersection(self, other: Self) -> Self {
+        if self.is_null() || other.is_null() {
+            return Self::NULL;
+        }
+        let (a, b) = (self.normalize(), other.normalize());
+        let min_x = a.origin.x.max(b.origin.x);
+        let min_y = a.origin.y.max(b.origin.y);
+        let max_x = a.max().x.min(b.max().x);
+        let max_y = a.max().y.min(b.max().y);
+        if max_x < min_x || max_y < min_y {
+            return Self::NULL;
+        }
+        Self::new(
+            CGPoint::new(min_x, min_y),
+            CGSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// The smallest rectangle that contains both rectangles.
+    ///
+    /// [The null rectangle][Self::NULL] is ignored, while empty rectangles
+    /// are not.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let a = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(1.0, 1.0));
+    /// let b = CGRect::new(CGPoint::new(2.0, 3.0), CGSize::new(1.0, 1.0));
+    /// let expected = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(3.0, 4.0));
+    /// assert_eq!(a.union(b), expected);
+    /// assert_eq!(a.union(CGRect::NULL), a);
+    /// ```
+    #[doc(alias = "CGRectUnion")]
+    pub fn union(self, other: Self) -> Self {
+        if self.is_null() {
+            return other;
+        }
+        if other.is_null() {
+            return self;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return Self::INFINITE;
+        }
+        let (a, b) = (self.normalize(), other.normalize());
+        let min_x = a.origin.x.min(b.origin.x);
+        let min_y = a.origin.y.min(b.origin.y);
+        let max_x = a.max().x.max(b.max().x);
+        let max_y = a.max().y.max(b.max().y);
+        Self::new(
+            CGPoint::new(min_x, min_y),
+            CGSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// Shrink the rectangle by `dx` on the left and right, and by `dy` on
+    /// the top and bottom (or grow it, if the values are negative).
+    ///
+    /// Returns [the null rectangle][Self::NULL] if the rectangle is null, or
+    /// if the resulting width or height would be negative. The infinite
+    /// rectangle is returned unchanged.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let rect = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(4.0, 4.0));
+    /// let expected = CGRect::new(CGPoint::new(1.0, 0.5), CGSize::new(2.0, 3.0));
+    /// assert_eq!(rect.inset(1.0, 0.5), expected);
+    /// assert!(rect.inset(3.0, 0.0).is_null());
+    /// ```
+    #[doc(alias = "CGRectInset")]
+    pub fn inset(self, dx: CGFloat, dy: CGFloat) -> Self {
+        if self.is_null() || self.is_infinite() {
+            return self;
+        }
+        let rect = self.normalize();
+        let size = CGSize::new(rect.size.width - 2.0 * dx, rect.size.height - 2.0 * dy);
+        if size.width < 0.0 || size.height < 0.0 {
+            return Self::NULL;
+        }
+        Self::new(CGPoint::new(rect.origin.x + dx, rect.origin.y + dy), size)
+    }
+
     // TODO: NSContainsRect / CGRectContainsRect
     // TODO: NSDivideRect / CGRectDivide
-    // TODO: NSInsetRect / CGRectInset
     // TODO: NSIntegralRect / CGRectIntegral
-    // TODO: NSIntersectionRect / CGRectIntersection
-    // TODO: NSUnionRect / CGRectUnion
     // TODO: NSIntersectsRect / CGRectIntersectsRect
     // TODO: NSMouseInRect
     // TODO: NSMouseInRect
-    // TODO: NSPointInRect / CGRectContainsPoint
     // TODO: NSOffsetRect / CGRectOffset
 
-    // TODO: CGRectIsNull
-    // TODO: CGRectIsInfinite
-    // TODO: CGRectInfinite
-    // TODO: CGRectNull
-
     // TODO: NSHeight / CGRectGetHeight (standardized)
     // TODO: NSWidth / CGRectGetWidth (standardized)
 }
@@ -385,4 +568,97 @@ mod tests {
         CGSize::new(-1.0, 1.0);
         CGSize::new(1.0, -1.0);
     }
+
+    fn rect(x: CGFloat, y: CGFloat, width: CGFloat, height: CGFloat) -> CGRect {
+        CGRect::new(CGPoint::new(x, y), CGSize::new(width, height))
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let r = rect(1.0, 2.0, 3.0, 4.0);
+        assert!(r.contains_point(CGPoint::new(1.0, 2.0)));
+        assert!(r.contains_point(CGPoint::new(3.9, 5.9)));
+        assert!(!r.contains_point(CGPoint::new(4.0, 2.0)));
+        assert!(!r.contains_point(CGPoint::new(1.0, 6.0)));
+        assert!(!r.contains_point(CGPoint::new(0.9, 3.0)));
+
+        // Negative sizes are normalized.
+        assert!(rect(4.0, 6.0, -3.0, -4.0).contains_point(CGPoint::new(1.0, 2.0)));
+
+        assert!(!CGRect::ZERO.contains_point(CGPoint::ZERO));
+        assert!(!CGRect::NULL.contains_point(CGPoint::ZERO));
+        assert!(CGRect::INFINITE.contains_point(CGPoint::new(1e30, -1e30)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = rect(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(
+            a.intersection(rect(2.0, 1.0, 4.0, 2.0)),
+            rect(2.0, 1.0, 2.0, 2.0)
+        );
+        assert_eq!(
+            a.intersection(rect(1.0, 1.0, 1.0, 1.0)),
+            rect(1.0, 1.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            a.intersection(rect(6.0, 3.0, -4.0, -2.0)),
+            rect(2.0, 1.0, 2.0, 2.0)
+        );
+        assert_eq!(a.intersection(a), a);
+
+        // Touching edges.
+        assert_eq!(
+            a.intersection(rect(4.0, 0.0, 1.0, 1.0)),
+            rect(4.0, 0.0, 0.0, 1.0)
+        );
+
+        // Empty intersection.
+        assert!(a.intersection(rect(5.0, 5.0, 1.0, 1.0)).is_null());
+        assert!(a.intersection(rect(0.0, 5.0, 4.0, 1.0)).is_null());
+
+        assert!(a.intersection(CGRect::NULL).is_null());
+        assert!(CGRect::NULL.intersection(a).is_null());
+        assert_eq!(a.intersection(CGRect::INFINITE), a);
+        assert_eq!(CGRect::INFINITE.intersection(a), a);
+    }
+
+    #[test]
+    fn test_union() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(
+            a.union(rect(2.0, -1.0, 1.0, 1.0)),
+            rect(0.0, -1.0, 3.0, 2.0)
+        );
+        assert_eq!(a.union(rect(0.5, 0.5, 0.1, 0.1)), a);
+        assert_eq!(
+            a.union(rect(3.0, 3.0, -1.0, -1.0)),
+            rect(0.0, 0.0, 3.0, 3.0)
+        );
+
+        // Empty rectangles are included.
+        assert_eq!(a.union(rect(5.0, 5.0, 0.0, 0.0)), rect(0.0, 0.0, 5.0, 5.0));
+
+        assert_eq!(a.union(CGRect::NULL), a);
+        assert_eq!(CGRect::NULL.union(a), a);
+        assert!(CGRect::NULL.union(CGRect::NULL).is_null());
+        assert!(a.union(CGRect::INFINITE).is_infinite());
+    }
+
+    #[test]
+    fn test_inset() {
+        let a = rect(0.0, 0.0, 4.0, 6.0);
+        assert_eq!(a.inset(1.0, 2.0), rect(1.0, 2.0, 2.0, 2.0));
+        assert_eq!(a.inset(-1.0, -1.0), rect(-1.0, -1.0, 6.0, 8.0));
+        assert_eq!(a.inset(2.0, 3.0), rect(2.0, 3.0, 0.0, 0.0));
+        assert_eq!(
+            rect(4.0, 6.0, -4.0, -6.0).inset(1.0, 2.0),
+            rect(1.0, 2.0, 2.0, 2.0)
+        );
+
+        assert!(a.inset(2.5, 0.0).is_null());
+        assert!(a.inset(0.0, 3.5).is_null());
+        assert!(CGRect::NULL.inset(-1.0, -1.0).is_null());
+        assert!(CGRect::INFINITE.inset(1.0, 1.0).is_infinite());
+    }
 }
@@ -6,6 +6,12 @@ use core::mem;
 use core::ptr::NonNull;
 
 use objc2::rc::{Retained, RetainedFromIterator};
+#[cfg(feature = "NSObject")]
+use objc2::runtime::AnyObject;
+#[cfg(all(feature = "NSDictionary", feature = "NSObject"))]
+use objc2::runtime::ProtocolObject;
+#[cfg(feature = "NSObject")]
+use objc2::DowncastTarget;
 use objc2::{msg_send, AnyThread, Message};
 
 #[cfg(feature = "NSEnumerator")]
@@ -29,6 +35,18 @@ impl<ObjectType: Message> NSArray<ObjectType> {
     ///     ns_string!("ghi"),
     /// ]);
     /// ```
+    ///
+    /// To create an array from an iterator of references instead, use
+    /// [`collect`][Iterator::collect], which retains each object.
+    ///
+    /// ```
+    /// use objc2::rc::Retained;
+    /// use objc2_foundation::{NSArray, NSString};
+    ///
+    /// let strings = ["abc", "def", "ghi"].map(NSString::from_str);
+    /// let array: Retained<NSArray<NSString>> = strings.iter().map(|s| &**s).collect();
+    /// # assert_eq!(array.count(), 3);
+    /// ```
     #[doc(alias = "initWithObjects:count:")]
     pub fn from_slice(slice: &[&ObjectType]) -> Retained<Self> {
         let len = slice.len();
@@ -228,14 +246,165 @@ impl<ObjectType: Message> NSArray<ObjectType> {
         vec.into_iter().map(ObjectType::retain).collect()
     }
 
+    /// Convert the array to an array of a more specific element type, if all
+    /// the elements are of that type.
+    ///
+    /// This checks each element with `isKindOfClass:`, and is the safe way
+    /// of refining e.g. an `NSArray<NSObject>` retrieved from a dynamic
+    /// source. See [`Retained::downcast`] for details on which types can be
+    /// downcast to.
+    ///
+    /// Since the array may actually be an `NSMutableArray` that could be
+    /// mutated later on, this works on a copy of the array (which for
+    /// immutable arrays is just another reference to the same array).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, NSObject, NSString};
+    ///
+    /// let array = NSArray::from_retained_slice(&[NSString::from_str("a").into_super()]);
+    /// let strings = array.try_downcast_elements::<NSString>().unwrap();
+    /// assert_eq!(strings.objectAtIndex(0).to_string(), "a");
+    /// ```
+    #[cfg(feature = "NSObject")]
+    pub fn try_downcast_elements<U: DowncastTarget>(&self) -> Option<Retained<NSArray<U>>> {
+        use crate::NSCopying;
+
+        let array = self.copy();
+        for i in 0..array.len() {
+            let obj = array.objectAtIndex(i);
+            // SAFETY: All objects are valid to re-interpret as `AnyObject`.
+            let obj: &AnyObject = unsafe { &*Retained::as_ptr(&obj).cast() };
+            obj.downcast_ref::<U>()?;
+        }
+        // SAFETY: Just checked that all elements are of type `U`, and the
+        // array is immutable, so that cannot change later on.
+        Some(unsafe { Retained::cast_unchecked(array) })
+    }
+
     /// Iterate over the array's elements.
+    ///
+    /// This uses fast enumeration, which fetches the elements from the
+    /// array in batches, and panics if the array is mutated while iterating.
+    ///
+    /// This is also what is used when iterating over `&NSArray` in a `for`
+    /// loop.
     #[cfg(feature = "NSEnumerator")]
     #[doc(alias = "objectEnumerator")]
+    #[doc(alias = "countByEnumeratingWithState:objects:count:")]
     #[inline]
     pub fn iter(&self) -> Iter<'_, ObjectType> {
         Iter(iter::Iter::new(self))
     }
 
+    /// Iterate over the array's elements in reverse order.
+    ///
+    /// This does not copy the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray};
+    ///
+    /// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// let mut iter = array.rev_iter();
+    /// assert_eq!(iter.next().unwrap().to_string(), "b");
+    /// assert_eq!(iter.next().unwrap().to_string(), "a");
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[cfg(feature = "NSEnumerator")]
+    #[doc(alias = "reverseObjectEnumerator")]
+    #[inline]
+    pub fn rev_iter(&self) -> RevIter<'_, ObjectType> {
+        // SAFETY: The iterator checks for mutation while enumerating.
+        let enumerator = unsafe { self.reverseObjectEnumerator() };
+        // SAFETY: The enumerator came from the array.
+        RevIter(unsafe { iter::IterWithBackingEnum::new(self, enumerator) })
+    }
+
+    /// Create a new array with the elements in reverse order.
+    ///
+    /// See [`rev_iter`][Self::rev_iter] for iterating in reverse order
+    /// without creating a new array.
+    pub fn reversed(&self) -> Retained<NSArray<ObjectType>> {
+        let mut vec = self.to_vec();
+        vec.reverse();
+        NSArray::from_retained_slice(&vec)
+    }
+
+    /// Call a closure with the index of each element in the array, along
+    /// with the element itself.
+    ///
+    /// This is a safe interface to `enumerateObjectsUsingBlock:`, and is
+    /// equivalent to (though may be faster than) `iter().enumerate()`.
+    ///
+    /// # Panics
+    ///
+    /// The array must not be mutated inside the closure, otherwise an
+    /// exception is thrown.
+    ///
+    /// If the closure panics, the panic is caught before it reaches the
+    /// Objective-C enumeration, the enumeration is stopped, and the panic is
+    /// resumed once the enumeration has returned. Without the `"std"`
+    /// feature, panics cannot be caught, and instead unwind through the
+    /// enumeration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray};
+    ///
+    /// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// array.for_each_indexed(|i, s| println!("{i}: {s}"));
+    /// ```
+    #[cfg(feature = "block2")]
+    #[doc(alias = "enumerateObjectsUsingBlock:")]
+    pub fn for_each_indexed<F: FnMut(usize, &ObjectType)>(&self, f: F) {
+        use block2::StackBlock;
+        use core::cell::RefCell;
+        use objc2::runtime::Bool;
+
+        // The block is `Fn`, so wrap the closure in a `RefCell`.
+        let f = RefCell::new(f);
+        // The payload of a panic in the closure.
+        #[cfg(feature = "std")]
+        let panic = core::cell::Cell::new(None);
+        let block = StackBlock::new(
+            |obj: NonNull<ObjectType>, index: usize, stop: NonNull<Bool>| {
+                // SAFETY: The object is valid, and is kept alive by the
+                // array for the duration of the enumeration.
+                let obj = unsafe { obj.as_ref() };
+
+                #[cfg(feature = "std")]
+                {
+                    // Don't unwind into Objective-C, stop the enumeration
+                    // and resume the panic afterwards instead.
+                    let res = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                        (f.borrow_mut())(index, obj)
+                    }));
+                    if let Err(payload) = res {
+                        panic.set(Some(payload));
+                        // SAFETY: The stop pointer is valid for writes.
+                        unsafe { stop.as_ptr().write(Bool::YES) };
+                    }
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    let _ = stop;
+                    (f.borrow_mut())(index, obj);
+                }
+            },
+        );
+
+        self.enumerateObjectsUsingBlock(&block);
+
+        #[cfg(feature = "std")]
+        if let Some(payload) = panic.take() {
+            std::panic::resume_unwind(payload)
+        }
+    }
+
     /// Returns the objects within the given range.
     ///
     /// # Panics
@@ -267,6 +436,186 @@ impl<ObjectType: Message> NSArray<ObjectType> {
     }
 }
 
+/// Conversion methods.
+impl<CopiedKey: Message> NSArray<CopiedKey> {
+    /// Create a dictionary by pairing each object in the array with the
+    /// object at the same index in `values`.
+    ///
+    /// If the arrays have different lengths, the extra objects in the
+    /// longer array are ignored.
+    ///
+    /// This is a safe interface to `dictionaryWithObjects:forKeys:`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray};
+    ///
+    /// let keys = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// let values = NSArray::from_slice(&[ns_string!("1"), ns_string!("2")]);
+    ///
+    /// let dict = keys.zip_into_dictionary(&values);
+    /// assert_eq!(&*dict.objectForKey(ns_string!("b")).unwrap(), ns_string!("2"));
+    /// ```
+    #[cfg(all(feature = "NSDictionary", feature = "NSObject", feature = "NSRange"))]
+    #[doc(alias = "dictionaryWithObjects:forKeys:")]
+    pub fn zip_into_dictionary<ObjectType: Message>(
+        &self,
+        values: &NSArray<ObjectType>,
+    ) -> Retained<crate::NSDictionary<CopiedKey::Result, ObjectType>>
+    where
+        // Same as in `NSDictionary::from_slices`, the dictionary copies its
+        // keys.
+        CopiedKey: crate::NSCopying + crate::CopyingHelper,
+    {
+        let len = self.len().min(values.len());
+        let keys = if self.len() == len {
+            self.retain()
+        } else {
+            self.subarrayWithRange(crate::NSRange::new(0, len))
+        };
+        let values = if values.len() == len {
+            values.retain()
+        } else {
+            values.subarrayWithRange(crate::NSRange::new(0, len))
+        };
+
+        // SAFETY: `CopiedKey` is `Message + NSCopying`, and is therefore safe
+        // to cast to `ProtocolObject<dyn NSCopying>`.
+        let keys = unsafe { keys.cast_unchecked::<ProtocolObject<dyn crate::NSCopying>>() };
+
+        // SAFETY:
+        // - The keys are `NSCopying`, and `CopyingHelper` ensures that the
+        //   key type is the type of the copied keys.
+        // - The arrays have the same length.
+        unsafe { crate::NSDictionary::dictionaryWithObjects_forKeys(&values, keys) }
+    }
+}
+
+impl<ObjectType: Message> NSArray<ObjectType> {
+    /// Group the objects in the array by the key computed by `key_fn`.
+    ///
+    /// The objects in each group are in the same order as in the array.
+    /// Keys are compared with `isEqual:`, as in any other dictionary.
+    ///
+    /// # Examples
+    ///
+    /// Group numbers by parity.
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray, NSNumber, NSString};
+    ///
+    /// let numbers = NSArray::from_retained_slice(&[
+    ///     NSNumber::new_i32(1),
+    ///     NSNumber::new_i32(2),
+    ///     NSNumber::new_i32(3),
+    /// ]);
+    /// let groups = numbers.group_by(|n| {
+    ///     NSString::from_str(if n.as_i32() % 2 == 0 { "even" } else { "odd" })
+    /// });
+    /// assert_eq!(groups.objectForKey(ns_string!("odd")).unwrap().len(), 2);
+    /// ```
+    #[cfg(all(feature = "NSDictionary", feature = "NSObject"))]
+    pub fn group_by<CopiedKey, F>(
+        &self,
+        mut key_fn: F,
+    ) -> Retained<crate::NSDictionary<CopiedKey::Result, NSArray<ObjectType>>>
+    where
+        CopiedKey: Message + crate::NSCopying + crate::CopyingHelper,
+        F: FnMut(&ObjectType) -> Retained<CopiedKey>,
+    {
+        let groups = crate::NSMutableDictionary::<
+            ProtocolObject<dyn crate::NSCopying>,
+            NSMutableArray<ObjectType>,
+        >::new();
+
+        // Work on a copy, in case `key_fn` mutates the array.
+        for obj in self.to_vec() {
+            let key = key_fn(&obj);
+            let key = ProtocolObject::from_ref(&*key);
+            if let Some(group) = groups.objectForKey(key) {
+                group.addObject(&obj);
+            } else {
+                let group = NSMutableArray::from_retained_slice(&[obj]);
+                // SAFETY: The key is `NSCopying`, and the dictionary is
+                // typed to contain such keys.
+                unsafe { groups.setObject_forKey(&group, key) };
+            }
+        }
+
+        // SAFETY:
+        // - `NSMutableDictionary` is a subclass of `NSDictionary`, and
+        //   `NSMutableArray` is a subclass of `NSArray`.
+        // - The dictionary copied the keys, which `CopyingHelper` ensures
+        //   are of type `CopiedKey::Result`.
+        // - The mutable objects are not accessible elsewhere, so they won't
+        //   be mutated while typed as immutable.
+        unsafe { Retained::cast_unchecked(groups) }
+    }
+
+    /// Create a new array with consecutive elements that compare equal
+    /// removed, keeping the first element of each run.
+    ///
+    /// If the array is sorted according to `compare`, this removes all
+    /// duplicates. This is similar to [`Vec::dedup_by`], except that it
+    /// leaves `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, NSNumber};
+    ///
+    /// let array = NSArray::from_retained_slice(&[
+    ///     NSNumber::new_i32(1),
+    ///     NSNumber::new_i32(1),
+    ///     NSNumber::new_i32(2),
+    /// ]);
+    /// let deduped = array.dedup_consecutive(|a, b| a.as_i32().cmp(&b.as_i32()));
+    /// assert_eq!(deduped.len(), 2);
+    /// ```
+    pub fn dedup_consecutive<F: FnMut(&ObjectType, &ObjectType) -> core::cmp::Ordering>(
+        &self,
+        mut compare: F,
+    ) -> Retained<NSArray<ObjectType>> {
+        let mut deduped: Vec<Retained<ObjectType>> = Vec::with_capacity(self.len());
+        // Work on a copy, in case `compare` mutates the array.
+        for obj in self.to_vec() {
+            match deduped.last() {
+                Some(last) if compare(last, &obj) == core::cmp::Ordering::Equal => {}
+                _ => deduped.push(obj),
+            }
+        }
+        NSArray::from_retained_slice(&deduped)
+    }
+}
+
+/// Conversion to data.
+#[cfg(all(feature = "NSData", feature = "NSEnumerator", feature = "NSValue"))]
+impl NSArray<crate::NSNumber> {
+    /// Pack the numbers in the array into a data object, one byte per
+    /// number.
+    ///
+    /// Each number is converted with [`NSNumber::as_u8`], so numbers that
+    /// don't fit in a byte are truncated.
+    ///
+    /// [`NSNumber::as_u8`]: crate::NSNumber::as_u8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, NSNumber};
+    ///
+    /// let array = NSArray::from_retained_slice(&[1, 2, 3].map(NSNumber::new_u8));
+    /// let data = array.to_data_u8();
+    /// assert_eq!(data.to_vec(), [1, 2, 3]);
+    /// assert_eq!(data.to_number_array(), array);
+    /// ```
+    pub fn to_data_u8(&self) -> Retained<crate::NSData> {
+        let bytes: Vec<u8> = self.iter().map(|number| number.as_u8()).collect();
+        crate::NSData::with_bytes(&bytes)
+    }
+}
+
 /// Convenience mutation methods.
 impl<ObjectType: Message> NSMutableArray<ObjectType> {
     /// Insert an object into the array at the given index.
@@ -289,9 +638,43 @@ impl<ObjectType: Message> NSMutableArray<ObjectType> {
     }
 
     /// Sort the array by the given comparison closure.
+    ///
+    /// # Panics
+    ///
+    /// If the closure panics, the panic is caught before it reaches the
+    /// Objective-C sorting routine, the remaining comparisons are treated as
+    /// equal, and the panic is resumed once sorting has finished. The order
+    /// of the array is unspecified in that case.
+    ///
+    /// Without the `"std"` feature, panics cannot be caught, and instead
+    /// unwind through the sorting routine.
+    ///
+    /// # Examples
+    ///
+    /// Sort numbers in descending order.
+    ///
+    /// ```
+    /// use objc2_foundation::{NSMutableArray, NSNumber};
+    ///
+    /// let array = NSMutableArray::from_retained_slice(&[
+    ///     NSNumber::new_i32(2),
+    ///     NSNumber::new_i32(3),
+    ///     NSNumber::new_i32(1),
+    /// ]);
+    /// array.sort_by(|a, b| b.as_i32().cmp(&a.as_i32()));
+    /// assert_eq!(array.objectAtIndex(0).as_i32(), 3);
+    /// assert_eq!(array.objectAtIndex(2).as_i32(), 1);
+    /// ```
     #[cfg(feature = "NSObjCRuntime")]
     #[doc(alias = "sortUsingFunction:context:")]
     pub fn sort_by<F: FnMut(&ObjectType, &ObjectType) -> core::cmp::Ordering>(&self, compare: F) {
+        struct Context<F> {
+            closure: F,
+            /// The payload of the first panic in the closure.
+            #[cfg(feature = "std")]
+            panic: Option<alloc::boxed::Box<dyn core::any::Any + Send>>,
+        }
+
         unsafe extern "C-unwind" fn compare_with_closure<
             ObjectType,
             F: FnMut(&ObjectType, &ObjectType) -> core::cmp::Ordering,
@@ -300,28 +683,79 @@ impl<ObjectType: Message> NSMutableArray<ObjectType> {
             obj2: core::ptr::NonNull<ObjectType>,
             context: *mut core::ffi::c_void,
         ) -> isize {
-            let context: *mut F = context.cast();
-            // Bring back a reference to the closure.
+            let context: *mut Context<F> = context.cast();
+            // Bring back a reference to the context.
             // Guaranteed to be unique, we gave `sortUsingFunction` unique
             // ownership, and that method only runs one function at a time.
-            let closure: &mut F = unsafe { context.as_mut().unwrap_unchecked() };
+            let context: &mut Context<F> = unsafe { context.as_mut().unwrap_unchecked() };
 
             // SAFETY: The objects are guaranteed to be valid
             let (obj1, obj2) = unsafe { (obj1.as_ref(), obj2.as_ref()) };
 
-            crate::NSComparisonResult::from((*closure)(obj1, obj2)) as _
+            #[cfg(feature = "std")]
+            let result = {
+                if context.panic.is_some() {
+                    // Finish sorting as fast as possible.
+                    return crate::NSComparisonResult::Same as _;
+                }
+                // Don't unwind into Objective-C, it may leave the array in
+                // an inconsistent state.
+                let closure = &mut context.closure;
+                match std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                    closure(obj1, obj2)
+                })) {
+                    Ok(ordering) => crate::NSComparisonResult::from(ordering),
+                    Err(payload) => {
+                        context.panic = Some(payload);
+                        crate::NSComparisonResult::Same
+                    }
+                }
+            };
+            #[cfg(not(feature = "std"))]
+            let result = crate::NSComparisonResult::from((context.closure)(obj1, obj2));
+
+            result as _
         }
 
         // Create function pointer
         let f: unsafe extern "C-unwind" fn(_, _, _) -> _ = compare_with_closure::<ObjectType, F>;
 
-        // Grab a type-erased pointer to the closure (a pointer to stack).
-        let mut closure = compare;
-        let context: *mut F = &mut closure;
+        // Grab a type-erased pointer to the context (a pointer to stack).
+        let mut context = Context {
+            closure: compare,
+            #[cfg(feature = "std")]
+            panic: None,
+        };
+        let context_ptr: *mut Context<F> = &mut context;
+
+        unsafe { self.sortUsingFunction_context(f, context_ptr.cast()) };
+
+        #[cfg(feature = "std")]
+        if let Some(payload) = context.panic {
+            std::panic::resume_unwind(payload);
+        }
+    }
 
-        unsafe { self.sortUsingFunction_context(f, context.cast()) };
-        // Keep the closure alive until the function has run.
-        drop(closure);
+    /// Insert an object into a sorted array, keeping the array sorted.
+    ///
+    /// The array must already be sorted according to `compare` (e.g. with
+    /// [`sort_by`][Self::sort_by]), otherwise the position at which the
+    /// object is inserted is unspecified. The position is found with a
+    /// binary search, and the object is inserted after any elements that
+    /// compare equal to it.
+    ///
+    /// Returns the index at which the object was inserted.
+    #[doc(alias = "indexOfObject:inSortedRange:options:usingComparator:")]
+    pub fn insert_sorted_by<F: FnMut(&ObjectType, &ObjectType) -> core::cmp::Ordering>(
+        &self,
+        obj: &ObjectType,
+        mut compare: F,
+    ) -> usize {
+        let index = util::partition_point(self.len(), |i| {
+            compare(&self.objectAtIndex(i), obj) != core::cmp::Ordering::Greater
+        });
+        self.insertObject_atIndex(obj, index);
+        index
     }
 }
 
@@ -369,7 +803,25 @@ __impl_iter! {
     impl<'a, ObjectType: Message> Iterator<Item = &'a ObjectType> for IterUnchecked<'a, ObjectType> { ... }
 }
 
+/// An iterator over the items of an array in reverse order.
+#[derive(Debug)]
+#[cfg(feature = "NSEnumerator")]
+pub struct RevIter<'a, ObjectType: Message>(
+    iter::IterWithBackingEnum<'a, NSArray<ObjectType>, crate::NSEnumerator<ObjectType>>,
+);
+
+#[cfg(feature = "NSEnumerator")]
+__impl_iter! {
+    impl<'a, ObjectType: Message> Iterator<Item = Retained<ObjectType>> for RevIter<'a, ObjectType> { ... }
+}
+
 /// A retained iterator over the items of an array.
+///
+/// This is created by calling `into_iter` on a `Retained<NSArray<T>>`. Each
+/// item is retained as it is produced, so the items may outlive the array.
+///
+/// The iterator uses fast enumeration internally, so it only supports
+/// iterating forwards. Use [`NSArray::to_vec`] if you need random access.
 #[derive(Debug)]
 #[cfg(feature = "NSEnumerator")]
 pub struct IntoIter<ObjectType: Message>(iter::IntoIter<NSArray<ObjectType>>);
@@ -34,6 +34,31 @@ impl NSAttributedString {
     pub fn from_nsstring(string: &NSString) -> Retained<Self> {
         Self::initWithString(Self::alloc(), string)
     }
+
+    /// The value of the attribute with the given name at the given UTF-16
+    /// index, along with the range over which the value applies.
+    ///
+    /// Returns `None` if there is no such attribute at the index.
+    ///
+    /// Note that the returned range is not necessarily the longest range
+    /// that the attribute applies to, see the documentation for
+    /// `attribute:atIndex:effectiveRange:`.
+    ///
+    /// # Panics
+    ///
+    /// Panics (by raising an exception) if the index is out of bounds.
+    #[doc(alias = "attribute:atIndex:effectiveRange:")]
+    #[cfg(all(feature = "NSRange", feature = "NSString"))]
+    pub fn attribute(
+        &self,
+        name: &NSAttributedStringKey,
+        index: usize,
+    ) -> Option<(Retained<objc2::runtime::AnyObject>, core::ops::Range<usize>)> {
+        let mut range = NSRange::new(0, 0);
+        // SAFETY: The range pointer is valid for writes.
+        let value = unsafe { self.attribute_atIndex_effectiveRange(name, index, &mut range) }?;
+        Some((value, range.into()))
+    }
 }
 
 impl NSMutableAttributedString {
@@ -50,3 +75,118 @@ impl NSMutableAttributedString {
         Self::initWithAttributedString(Self::alloc(), attributed_string)
     }
 }
+
+#[cfg(all(
+    feature = "NSDictionary",
+    feature = "NSObject",
+    feature = "NSRange",
+    feature = "NSString"
+))]
+type Attributes = NSDictionary<NSAttributedStringKey, objc2::runtime::AnyObject>;
+
+/// A helper for building an [`NSAttributedString`] from runs of text with
+/// different attributes.
+///
+/// The ranges of the attributes are computed in UTF-16 code units, and
+/// adjacent runs with equal attributes are applied in a single
+/// `setAttributes:range:` call.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::runtime::AnyObject;
+/// use objc2_foundation::{ns_string, AttributedStringBuilder, NSDictionary};
+///
+/// let link: &AnyObject = ns_string!("https://example.com");
+/// let attributes = NSDictionary::from_slices(&[ns_string!("NSLink")], &[link]);
+///
+/// let mut builder = AttributedStringBuilder::new();
+/// builder.push_str("Visit ");
+/// // SAFETY: The `NSLink` attribute takes a string or an URL.
+/// unsafe { builder.push_str_with_attributes("our website", &attributes) };
+/// let string = builder.finish();
+///
+/// assert_eq!(string.string().to_string(), "Visit our website");
+/// assert!(string.attribute(ns_string!("NSLink"), 0).is_none());
+/// assert_eq!(string.attribute(ns_string!("NSLink"), 8).unwrap().1, 6..17);
+/// ```
+#[cfg(all(
+    feature = "NSDictionary",
+    feature = "NSObject",
+    feature = "NSRange",
+    feature = "NSString"
+))]
+#[derive(Debug, Default)]
+pub struct AttributedStringBuilder {
+    string: alloc::string::String,
+    utf16_len: usize,
+    runs: alloc::vec::Vec<(NSRange, Retained<Attributes>)>,
+}
+
+#[cfg(all(
+    feature = "NSDictionary",
+    feature = "NSObject",
+    feature = "NSRange",
+    feature = "NSString"
+))]
+impl AttributedStringBuilder {
+    /// Create a new, empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, string: &str) -> NSRange {
+        let location = self.utf16_len;
+        let length = string.encode_utf16().count();
+        self.string.push_str(string);
+        self.utf16_len += length;
+        NSRange::new(location, length)
+    }
+
+    /// Append a string without any attributes.
+    pub fn push_str(&mut self, string: &str) -> &mut Self {
+        self.push(string);
+        self
+    }
+
+    /// Append a string with the given attributes.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The attributes must be valid, see
+    /// [`NSAttributedString::new_with_attributes`].
+    pub unsafe fn push_str_with_attributes(
+        &mut self,
+        string: &str,
+        attributes: &NSDictionary<NSAttributedStringKey, objc2::runtime::AnyObject>,
+    ) -> &mut Self {
+        let range = self.push(string);
+        if range.is_empty() {
+            return self;
+        }
+        let other: &objc2::runtime::AnyObject = attributes;
+        match self.runs.last_mut() {
+            Some((last, last_attributes))
+                if last.end() == range.location && last_attributes.isEqual(Some(other)) =>
+            {
+                last.length += range.length;
+            }
+            _ => self.runs.push((range, attributes.copy())),
+        }
+        self
+    }
+
+    /// Create the attributed string.
+    #[doc(alias = "setAttributes:range:")]
+    pub fn finish(&self) -> Retained<NSAttributedString> {
+        let string = NSMutableAttributedString::from_nsstring(&NSString::from_str(&self.string));
+        for (range, attributes) in &self.runs {
+            // SAFETY: The attributes were checked to be valid when pushed,
+            // and the range is in bounds.
+            unsafe { string.setAttributes_range(Some(attributes), *range) };
+        }
+        string.into_super()
+    }
+}
@@ -13,4 +13,33 @@ impl NSBundle {
         let name = info.objectForKey(crate::ns_string!("CFBundleName"))?;
         Some(name.downcast().expect("CFBundleName to be NSString"))
     }
+
+    /// Look up the localized version of a string in the bundle's strings
+    /// tables, similar to `NSLocalizedString` in Objective-C.
+    ///
+    /// The string is looked up in `table` (the `.strings` file with that
+    /// name), or in `Localizable.strings` if `None`. If no localized version
+    /// of the string is found, `key` is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::NSBundle;
+    ///
+    /// let bundle = NSBundle::mainBundle();
+    /// let title = bundle.localized_string("window.title", None);
+    /// println!("{title}");
+    /// ```
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "localizedStringForKey:value:table:")]
+    #[doc(alias = "NSLocalizedString")]
+    pub fn localized_string(
+        &self,
+        key: &str,
+        table: Option<&str>,
+    ) -> objc2::rc::Retained<crate::NSString> {
+        let key = crate::NSString::from_str(key);
+        let table = table.map(crate::NSString::from_str);
+        self.localizedStringForKey_value_table(&key, None, table.as_deref())
+    }
 }
@@ -2,7 +2,6 @@ use alloc::vec::Vec;
 use core::ffi::c_void;
 use core::fmt;
 use core::marker::PhantomData;
-#[cfg(feature = "NSRange")]
 use core::ops::Range;
 use core::panic::{RefUnwindSafe, UnwindSafe};
 use core::ptr::NonNull;
@@ -73,6 +72,11 @@ impl NSData {
 
     /// The bytes in the data.
     ///
+    /// `NSData` intentionally does not implement `Deref<Target = [u8]>` or
+    /// `AsRef<[u8]>`, since a `&NSData` may point to an `NSMutableData`
+    /// that can be mutated through a shared reference, which would
+    /// invalidate the slice (and `NSData` already derefs to `NSObject`).
+    ///
     /// # Safety
     ///
     /// The data must not be mutated while the returned slice is alive.
@@ -80,6 +84,23 @@ impl NSData {
     /// difficult to uphold.
     ///
     /// [`to_vec`]: Self::to_vec
+    ///
+    /// # Examples
+    ///
+    /// Pass the bytes to a function taking `&[u8]`.
+    ///
+    /// ```
+    /// use objc2_foundation::NSData;
+    ///
+    /// fn checksum(bytes: &[u8]) -> u8 {
+    ///     bytes.iter().fold(0, |acc, b| acc.wrapping_add(*b))
+    /// }
+    ///
+    /// let data = NSData::with_bytes(b"hello");
+    /// // SAFETY: The data is immutable, and is not mutated elsewhere.
+    /// let bytes = unsafe { data.as_bytes_unchecked() };
+    /// assert_eq!(checksum(bytes), 20);
+    /// ```
     pub unsafe fn as_bytes_unchecked(&self) -> &[u8] {
         let ptr = self.bytes_raw();
         if !ptr.is_null() {
@@ -121,15 +142,309 @@ impl NSData {
     pub fn iter(&self) -> Iter<'_> {
         Iter::new(self)
     }
+
+    /// Returns `true` if the data begins with the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// Check for the PNG signature.
+    ///
+    /// ```
+    /// use objc2_foundation::NSData;
+    ///
+    /// let data = NSData::with_bytes(b"\x89PNG\r\n\x1a\n...");
+    /// assert!(data.starts_with(b"\x89PNG\r\n\x1a\n"));
+    /// ```
+    pub fn starts_with(&self, needle: &[u8]) -> bool {
+        // SAFETY: The comparison does not call any methods that could mutate
+        // the data while the slice is alive.
+        unsafe { self.as_bytes_unchecked() }.starts_with(needle)
+    }
+
+    /// Returns `true` if the data ends with the given bytes.
+    pub fn ends_with(&self, needle: &[u8]) -> bool {
+        // SAFETY: Same as in `starts_with`.
+        unsafe { self.as_bytes_unchecked() }.ends_with(needle)
+    }
+
+    /// Returns the index of the first occurrence of the given bytes in the
+    /// data, or `None` if they are not present.
+    ///
+    /// An empty `needle` is found at index `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSData;
+    ///
+    /// let data = NSData::with_bytes(b"GET /index.html HTTP/1.1\r\n\r\n");
+    /// assert_eq!(data.find(b"\r\n"), Some(24));
+    /// assert_eq!(data.find(b"POST"), None);
+    /// ```
+    #[doc(alias = "rangeOfData:options:range:")]
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        // SAFETY: Same as in `starts_with`.
+        unsafe { self.as_bytes_unchecked() }
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    /// Returns the range of the first occurrence of the given bytes in the
+    /// data, or `None` if they are not present.
+    ///
+    /// See [`find`][Self::find] for details.
+    #[doc(alias = "rangeOfData:options:range:")]
+    pub fn find_range(&self, needle: &[u8]) -> Option<Range<usize>> {
+        self.find(needle).map(|start| start..start + needle.len())
+    }
+
+    /// Create a reader over the bytes of the data.
+    ///
+    /// The returned reader implements [`std::io::Read`], and can be used to
+    /// pass the data to APIs that expect a stream of bytes, without first
+    /// copying it into a [`Vec`].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    /// use objc2_foundation::NSData;
+    ///
+    /// let data = NSData::with_bytes(b"hello world");
+    /// let mut reader = data.reader();
+    ///
+    /// let mut buf = [0; 5];
+    /// reader.read_exact(&mut buf).unwrap();
+    /// assert_eq!(&buf, b"hello");
+    ///
+    /// let mut rest = String::new();
+    /// reader.read_to_string(&mut rest).unwrap();
+    /// assert_eq!(rest, " world");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn reader(&self) -> NSDataReader<'_> {
+        NSDataReader { data: self, pos: 0 }
+    }
+
+    /// Create a new data object with the bytes in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the data, or if the start of
+    /// the range is greater than the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSData;
+    ///
+    /// let data = NSData::with_bytes(b"hello world");
+    /// assert_eq!(*data.subdata(6..11), b"world"[..]);
+    /// ```
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "subdataWithRange:")]
+    pub fn subdata(&self, range: Range<usize>) -> Retained<NSData> {
+        let len = self.len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range {range:?} out of bounds of data with length {len}",
+        );
+        self.subdataWithRange(range.into())
+    }
+}
+
+/// Hexadecimal conversion.
+#[cfg(feature = "NSString")]
+impl NSData {
+    /// Encode the bytes as a hexadecimal string, using two digits per byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSData;
+    ///
+    /// let data = NSData::with_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+    /// assert_eq!(data.to_hex(false).to_string(), "deadbeef");
+    /// assert_eq!(data.to_hex(true).to_string(), "DEADBEEF");
+    /// ```
+    pub fn to_hex(&self, uppercase: bool) -> Retained<crate::NSString> {
+        let digits = if uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let mut hex = alloc::string::String::with_capacity(self.len() * 2);
+        for byte in self.iter() {
+            hex.push(digits[(byte >> 4) as usize] as char);
+            hex.push(digits[(byte & 0xf) as usize] as char);
+        }
+        crate::NSString::from_str(&hex)
+    }
+
+    /// Decode a hexadecimal string into data.
+    ///
+    /// Both uppercase and lowercase digits are accepted. Returns `None` if
+    /// the string has an odd number of digits, or contains characters that
+    /// are not hexadecimal digits.
+    pub fn from_hex(string: &crate::NSString) -> Option<Retained<Self>> {
+        fn digit(c: u8) -> Option<u8> {
+            match c {
+                b'0'..=b'9' => Some(c - b'0'),
+                b'a'..=b'f' => Some(c - b'a' + 10),
+                b'A'..=b'F' => Some(c - b'A' + 10),
+                _ => None,
+            }
+        }
+
+        let string = alloc::string::ToString::to_string(string);
+        let hex = string.as_bytes();
+        if hex.len() % 2 != 0 {
+            return None;
+        }
+        let bytes = hex
+            .chunks_exact(2)
+            .map(|pair| Some((digit(pair[0])? << 4) | digit(pair[1])?))
+            .collect::<Option<Vec<u8>>>()?;
+        Some(Self::with_bytes(&bytes))
+    }
+}
+
+/// Base64 conversion.
+#[cfg(feature = "NSString")]
+impl NSData {
+    /// Encode the bytes as a Base64 string.
+    ///
+    /// Use [`NSDataBase64EncodingOptions::Encoding64CharacterLineLength`] or
+    /// [`NSDataBase64EncodingOptions::Encoding76CharacterLineLength`] to
+    /// insert line endings, e.g. for MIME. The line endings are CRLF unless
+    /// only one of the `EncodingEndLineWith*` options is given.
+    ///
+    /// [`NSDataBase64EncodingOptions::Encoding64CharacterLineLength`]: crate::NSDataBase64EncodingOptions::Encoding64CharacterLineLength
+    /// [`NSDataBase64EncodingOptions::Encoding76CharacterLineLength`]: crate::NSDataBase64EncodingOptions::Encoding76CharacterLineLength
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSData, NSDataBase64EncodingOptions};
+    ///
+    /// let data = NSData::with_bytes(b"hello");
+    /// let base64 = data.to_base64(NSDataBase64EncodingOptions::empty());
+    /// assert_eq!(base64.to_string(), "aGVsbG8=");
+    /// ```
+    #[doc(alias = "base64EncodedStringWithOptions:")]
+    pub fn to_base64(
+        &self,
+        options: crate::NSDataBase64EncodingOptions,
+    ) -> Retained<crate::NSString> {
+        self.base64EncodedStringWithOptions(options)
+    }
+
+    /// Decode a Base64 string into data.
+    ///
+    /// Returns `None` if the string is not valid Base64. Line endings and
+    /// other characters outside the Base64 alphabet are only accepted with
+    /// [`NSDataBase64DecodingOptions::IgnoreUnknownCharacters`].
+    ///
+    /// [`NSDataBase64DecodingOptions::IgnoreUnknownCharacters`]: crate::NSDataBase64DecodingOptions::IgnoreUnknownCharacters
+    #[doc(alias = "initWithBase64EncodedString:options:")]
+    pub fn from_base64(
+        string: &crate::NSString,
+        options: crate::NSDataBase64DecodingOptions,
+    ) -> Option<Retained<Self>> {
+        Self::initWithBase64EncodedString_options(Self::alloc(), string, options)
+    }
+}
+
+/// Conversion to and from arrays of numbers.
+#[cfg(all(feature = "NSArray", feature = "NSValue"))]
+impl NSData {
+    /// Create an array with an [`NSNumber`][crate::NSNumber] for each byte
+    /// in the data.
+    ///
+    /// See [`NSArray::to_data_u8`][crate::NSArray::to_data_u8] for the
+    /// reverse operation.
+    pub fn to_number_array(&self) -> Retained<crate::NSArray<crate::NSNumber>> {
+        let numbers: Vec<_> = self.iter().map(crate::NSNumber::new_u8).collect();
+        crate::NSArray::from_retained_slice(&numbers)
+    }
+}
+
+/// [`Path`] methods.
+///
+/// [`Path`]: std::path::Path
+#[cfg(feature = "std")]
+#[cfg(unix)]
+#[cfg(not(feature = "gnustep-1-7"))]
+#[cfg(all(feature = "NSURL", feature = "NSError"))]
+impl NSData {
+    /// Write the data to the file at the given path.
+    ///
+    /// If `atomically` is `true`, the data is first written to a temporary
+    /// file, which is then renamed to the destination, so that readers never
+    /// observe a partially written file.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by Foundation if the file could not be
+    /// written, or an error with the `NSFileWriteInvalidFileNameError` code
+    /// if the path could not be converted to a file URL.
+    #[cfg(feature = "FoundationErrors")]
+    #[cfg(all(feature = "NSDictionary", feature = "NSString"))]
+    #[doc(alias = "writeToURL:options:error:")]
+    pub fn write_to_file(
+        &self,
+        path: &std::path::Path,
+        atomically: bool,
+    ) -> Result<(), Retained<crate::NSError>> {
+        use crate::{NSDataWritingOptions, NSError, NSURL};
+
+        let url = NSURL::from_file_path(path).ok_or_else(|| {
+            NSError::new(crate::NSFileWriteInvalidFileNameError, unsafe {
+                crate::NSCocoaErrorDomain
+            })
+        })?;
+        let options = if atomically {
+            NSDataWritingOptions::Atomic
+        } else {
+            NSDataWritingOptions::empty()
+        };
+        self.writeToURL_options_error(&url, options)
+    }
 }
 
 impl NSMutableData {
     /// A mutable view of the bytes in the data.
     ///
+    /// This is a zero-copy way of editing the bytes in place. Note that
+    /// there is no safe closure-based version of this, since the data may be
+    /// mutated through another reference to it while the closure runs.
+    ///
+    /// An empty data yields an empty slice.
+    ///
     /// # Safety
     ///
     /// No methods on the `NSMutableData` may be called while the returned
     /// slice is alive.
+    ///
+    /// # Examples
+    ///
+    /// Obfuscate the data with a XOR key.
+    ///
+    /// ```
+    /// use objc2_foundation::NSMutableData;
+    ///
+    /// let data = NSMutableData::with_bytes(b"secret");
+    /// // SAFETY: The data is not accessed while the slice is alive.
+    /// let bytes = unsafe { data.as_mut_bytes_unchecked() };
+    /// for byte in bytes {
+    ///     *byte ^= 0x5a;
+    /// }
+    /// assert_ne!(data.to_vec(), b"secret");
+    /// ```
     #[doc(alias = "mutableBytes")]
     #[allow(clippy::mut_from_ref)]
     pub unsafe fn as_mut_bytes_unchecked(&self) -> &mut [u8] {
@@ -173,6 +488,15 @@ impl NSMutableData {
     }
 }
 
+impl PartialEq<[u8]> for NSData {
+    /// Compare the bytes in the data with a byte slice, without copying.
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        // SAFETY: Same as in `starts_with`.
+        self.len() == other.len() && unsafe { self.as_bytes_unchecked() } == other
+    }
+}
+
 impl fmt::Debug for NSData {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -288,6 +612,11 @@ impl<'a> Extend<&'a u8> for &NSMutableData {
     }
 }
 
+/// Append bytes to the data.
+///
+/// Writing never fails, and always writes the entire buffer. This is also
+/// implemented for `Retained<NSMutableData>`, so that the data can be passed
+/// directly to e.g. [`std::io::copy`] or [`write!`].
 #[cfg(feature = "std")]
 impl std::io::Write for &NSMutableData {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
@@ -305,6 +634,35 @@ impl std::io::Write for &NSMutableData {
     }
 }
 
+/// A reader over the bytes in an `NSData`.
+///
+/// This is created with [`NSData::reader`].
+///
+/// If the data is mutated while being read from, the reader continues from
+/// the same offset into the new contents (and reaches the end immediately if
+/// the data has been shrunk past that offset).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct NSDataReader<'a> {
+    data: &'a NSData,
+    pos: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for NSDataReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // SAFETY: We only copy out of the slice, which does not call any
+        // methods that could mutate the data while the slice is alive.
+        let bytes = unsafe { self.data.as_bytes_unchecked() };
+        // The data may have been shrunk since the last read.
+        let remaining = bytes.get(self.pos..).unwrap_or(&[]);
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
 #[cfg(feature = "block2")]
 impl RetainedFromIterator<u8> for NSData {
     fn retained_from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Retained<Self> {
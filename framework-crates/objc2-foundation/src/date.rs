@@ -0,0 +1,64 @@
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "std")]
+use objc2::rc::Retained;
+
+#[cfg(feature = "std")]
+use crate::NSDate;
+
+/// Conversion to and from [`SystemTime`].
+#[cfg(feature = "std")]
+impl NSDate {
+    /// Create a date from a [`SystemTime`].
+    ///
+    /// `NSDate` stores the time as a floating-point number of seconds, so
+    /// this may lose precision for times far away from the year 2001.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use objc2_foundation::NSDate;
+    ///
+    /// let date = NSDate::from_system_time(UNIX_EPOCH + Duration::from_secs(1_000_000_000));
+    /// assert_eq!(date.timeIntervalSince1970(), 1_000_000_000.0);
+    /// ```
+    pub fn from_system_time(time: SystemTime) -> Retained<Self> {
+        let secs = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64(),
+            // Before 1970.
+            Err(err) => -err.duration().as_secs_f64(),
+        };
+        Self::dateWithTimeIntervalSince1970(secs)
+    }
+
+    /// Convert the date to a [`SystemTime`].
+    ///
+    /// Returns [`None`] if the date cannot be represented as a `SystemTime`
+    /// on this platform.
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let secs = self.timeIntervalSince1970();
+        let duration = Duration::try_from_secs_f64(secs.abs()).ok()?;
+        if secs >= 0.0 {
+            UNIX_EPOCH.checked_add(duration)
+        } else {
+            UNIX_EPOCH.checked_sub(duration)
+        }
+    }
+}
+
+/// Convert the date to a [`SystemTime`].
+///
+/// # Panics
+///
+/// Panics if the date cannot be represented as a `SystemTime`, see
+/// [`NSDate::to_system_time`] for a non-panicking version.
+#[cfg(feature = "std")]
+impl From<&NSDate> for SystemTime {
+    fn from(date: &NSDate) -> Self {
+        date.to_system_time()
+            .unwrap_or_else(|| panic!("date {date:?} out of range of SystemTime"))
+    }
+}
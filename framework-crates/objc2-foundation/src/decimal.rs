@@ -1,6 +1,16 @@
 use core::ffi::c_ushort;
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+use core::fmt;
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+use core::ops::{Add, Div, Mul, Sub};
 
 use objc2::encode::{Encode, Encoding, RefEncode};
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+use objc2::rc::Retained;
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+use objc2::runtime::ProtocolObject;
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+use objc2::AnyThread;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -31,3 +41,189 @@ unsafe impl Encode for NSDecimal {
 unsafe impl RefEncode for NSDecimal {
     const ENCODING_REF: Encoding = Encoding::Pointer(&Self::ENCODING);
 }
+
+impl NSDecimal {
+    fn length(&self) -> u32 {
+        ((self._inner >> 8) & 0xf) as u32
+    }
+
+    fn is_negative(&self) -> bool {
+        (self._inner >> 12) & 1 == 1
+    }
+
+    /// Whether the decimal is "not a number".
+    ///
+    /// This is the value that calculations result in on overflow or
+    /// division by zero.
+    #[doc(alias = "NSDecimalIsNotANumber")]
+    pub fn is_nan(&self) -> bool {
+        self.length() == 0 && self.is_negative()
+    }
+
+    #[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+    fn is_zero(&self) -> bool {
+        self.length() == 0 && !self.is_negative()
+    }
+}
+
+/// Arithmetic.
+///
+/// The calculations are done by `NSDecimalNumber`, and are thus the same as
+/// `NSDecimalAdd`, `NSDecimalSubtract`, `NSDecimalMultiply` and
+/// `NSDecimalDivide`.
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+impl NSDecimal {
+    fn calculate(
+        self,
+        other: Self,
+        rounding_mode: crate::NSRoundingMode,
+        f: impl FnOnce(
+            &crate::NSDecimalNumber,
+            &crate::NSDecimalNumber,
+            Option<&ProtocolObject<dyn crate::NSDecimalNumberBehaviors>>,
+        ) -> Retained<crate::NSDecimalNumber>,
+    ) -> Result<Self, crate::NSCalculationError> {
+        use crate::{NSCalculationError, NSDecimalNumber};
+
+        // Calculations with NaN result in NaN, that is not an error.
+        if self.is_nan() || other.is_nan() {
+            return Ok(NSDecimalNumber::notANumber().decimalValue());
+        }
+
+        let behavior = crate::decimal_number::non_raising_behavior(rounding_mode);
+        let lhs = NSDecimalNumber::initWithDecimal(NSDecimalNumber::alloc(), self);
+        let rhs = NSDecimalNumber::initWithDecimal(NSDecimalNumber::alloc(), other);
+        let res = f(&lhs, &rhs, Some(&*behavior)).decimalValue();
+
+        // Without raising, errors are signalled by returning NaN.
+        if res.is_nan() {
+            if other.is_zero() {
+                Err(NSCalculationError::DivideByZero)
+            } else {
+                Err(NSCalculationError::Overflow)
+            }
+        } else {
+            Ok(res)
+        }
+    }
+
+    /// Add two decimals, rounding with the given mode.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NSCalculationError::Overflow`] if the result is too large.
+    ///
+    /// [`NSCalculationError::Overflow`]: crate::NSCalculationError::Overflow
+    #[doc(alias = "NSDecimalAdd")]
+    pub fn checked_add(
+        self,
+        other: Self,
+        rounding_mode: crate::NSRoundingMode,
+    ) -> Result<Self, crate::NSCalculationError> {
+        self.calculate(other, rounding_mode, |lhs, rhs, behavior| {
+            lhs.decimalNumberByAdding_withBehavior(rhs, behavior)
+        })
+    }
+
+    /// Subtract two decimals, rounding with the given mode.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NSCalculationError::Overflow`] if the result is too large.
+    ///
+    /// [`NSCalculationError::Overflow`]: crate::NSCalculationError::Overflow
+    #[doc(alias = "NSDecimalSubtract")]
+    pub fn checked_sub(
+        self,
+        other: Self,
+        rounding_mode: crate::NSRoundingMode,
+    ) -> Result<Self, crate::NSCalculationError> {
+        self.calculate(other, rounding_mode, |lhs, rhs, behavior| {
+            lhs.decimalNumberBySubtracting_withBehavior(rhs, behavior)
+        })
+    }
+
+    /// Multiply two decimals, rounding with the given mode.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NSCalculationError::Overflow`] if the result is too large.
+    ///
+    /// [`NSCalculationError::Overflow`]: crate::NSCalculationError::Overflow
+    #[doc(alias = "NSDecimalMultiply")]
+    pub fn checked_mul(
+        self,
+        other: Self,
+        rounding_mode: crate::NSRoundingMode,
+    ) -> Result<Self, crate::NSCalculationError> {
+        self.calculate(other, rounding_mode, |lhs, rhs, behavior| {
+            lhs.decimalNumberByMultiplyingBy_withBehavior(rhs, behavior)
+        })
+    }
+
+    /// Divide two decimals, rounding with the given mode.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NSCalculationError::DivideByZero`] if `other` is zero, and
+    /// [`NSCalculationError::Overflow`] if the result is too large.
+    ///
+    /// [`NSCalculationError::DivideByZero`]: crate::NSCalculationError::DivideByZero
+    /// [`NSCalculationError::Overflow`]: crate::NSCalculationError::Overflow
+    #[doc(alias = "NSDecimalDivide")]
+    pub fn checked_div(
+        self,
+        other: Self,
+        rounding_mode: crate::NSRoundingMode,
+    ) -> Result<Self, crate::NSCalculationError> {
+        self.calculate(other, rounding_mode, |lhs, rhs, behavior| {
+            lhs.decimalNumberByDividingBy_withBehavior(rhs, behavior)
+        })
+    }
+}
+
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        /// Rounds with `NSRoundPlain`.
+        ///
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the calculation fails, see [`NSDecimal::", stringify!($checked), "`].")]
+        #[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+        impl $trait for NSDecimal {
+            type Output = Self;
+
+            #[track_caller]
+            fn $method(self, rhs: Self) -> Self {
+                match self.$checked(rhs, crate::NSRoundingMode::RoundPlain) {
+                    Ok(res) => res,
+                    Err(crate::NSCalculationError::DivideByZero) => {
+                        panic!("attempt to divide by zero")
+                    }
+                    Err(_) => panic!(concat!("attempt to ", stringify!($method), " with overflow")),
+                }
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, checked_add);
+impl_op!(Sub, sub, checked_sub);
+impl_op!(Mul, mul, checked_mul);
+impl_op!(Div, div, checked_div);
+
+/// Formats the decimal the same way as `NSDecimalString` without a locale.
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+impl fmt::Display for NSDecimal {
+    #[doc(alias = "NSDecimalString")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number =
+            crate::NSDecimalNumber::initWithDecimal(crate::NSDecimalNumber::alloc(), *self);
+        fmt::Display::fmt(&number, f)
+    }
+}
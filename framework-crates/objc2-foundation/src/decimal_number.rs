@@ -0,0 +1,105 @@
+#[cfg(feature = "NSObjCRuntime")]
+use core::cmp::Ordering;
+use core::fmt;
+#[cfg(feature = "NSDecimal")]
+use core::ops::{Add, Div, Mul, Sub};
+
+#[cfg(feature = "NSDecimal")]
+use objc2::rc::Retained;
+#[cfg(feature = "NSDecimal")]
+use objc2::runtime::ProtocolObject;
+
+use crate::NSDecimalNumber;
+
+/// A behavior that rounds with the given mode and doesn't limit the scale,
+/// but that returns `notANumber` instead of raising an exception on errors.
+///
+/// With `NSRoundPlain`, this rounds the same as the default behavior.
+/// Exceptions cannot unwind through Rust operator implementations.
+#[cfg(feature = "NSDecimal")]
+pub(crate) fn non_raising_behavior(
+    rounding_mode: crate::NSRoundingMode,
+) -> Retained<ProtocolObject<dyn crate::NSDecimalNumberBehaviors>> {
+    let handler = crate::NSDecimalNumberHandler::decimalNumberHandlerWithRoundingMode_scale_raiseOnExactness_raiseOnOverflow_raiseOnUnderflow_raiseOnDivideByZero(
+        rounding_mode,
+        // NSDecimalNoScale
+        i16::MAX,
+        false,
+        false,
+        false,
+        false,
+    );
+    ProtocolObject::from_retained(handler)
+}
+
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, $selector:ident, $doc:literal) => {
+        #[doc = $doc]
+        ///
+        /// Rounds with `NSRoundPlain`. If the operation overflows or divides
+        /// by zero, the result is [`NSDecimalNumber::notANumber`] instead of
+        /// an exception being raised.
+        #[cfg(feature = "NSDecimal")]
+        impl $trait<&NSDecimalNumber> for &NSDecimalNumber {
+            type Output = Retained<NSDecimalNumber>;
+
+            fn $method(self, rhs: &NSDecimalNumber) -> Self::Output {
+                let behavior = non_raising_behavior(crate::NSRoundingMode::RoundPlain);
+                self.$selector(rhs, Some(&*behavior))
+            }
+        }
+    };
+}
+
+impl_op!(
+    Add,
+    add,
+    decimalNumberByAdding_withBehavior,
+    "Add two decimal numbers."
+);
+impl_op!(
+    Sub,
+    sub,
+    decimalNumberBySubtracting_withBehavior,
+    "Subtract two decimal numbers."
+);
+impl_op!(
+    Mul,
+    mul,
+    decimalNumberByMultiplyingBy_withBehavior,
+    "Multiply two decimal numbers."
+);
+impl_op!(
+    Div,
+    div,
+    decimalNumberByDividingBy_withBehavior,
+    "Divide two decimal numbers."
+);
+
+/// Beware: This uses the Objective-C method "compare:", which considers
+/// `notANumber` to be smaller than all other numbers.
+#[cfg(feature = "NSObjCRuntime")]
+impl PartialOrd for NSDecimalNumber {
+    #[doc(alias = "compare:")]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Beware: This uses the Objective-C method "compare:", which considers
+/// `notANumber` to be smaller than all other numbers.
+#[cfg(feature = "NSObjCRuntime")]
+impl Ord for NSDecimalNumber {
+    #[doc(alias = "compare:")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `compare:` is overridden by `NSDecimalNumber`.
+        (**self).cmp(&**other)
+    }
+}
+
+impl fmt::Display for NSDecimalNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `stringValue` is overridden by `NSDecimalNumber`.
+        fmt::Display::fmt(&**self, f)
+    }
+}
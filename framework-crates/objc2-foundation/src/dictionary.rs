@@ -335,6 +335,39 @@ impl<KeyType: Message, ObjectType: Message> NSDictionary<KeyType, ObjectType> {
         Keys(iter::Iter::new(self))
     }
 
+    /// Iterate over the dictionary's key-value pairs.
+    ///
+    /// The keys are enumerated using fast enumeration, and each object is
+    /// then looked up with `objectForKey:`.
+    ///
+    /// The order of iteration is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the dictionary is mutated while iterating.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "NSString", doc = "```")]
+    #[cfg_attr(not(feature = "NSString"), doc = "```ignore")]
+    /// use objc2_foundation::{ns_string, NSMutableDictionary};
+    ///
+    /// let dict = NSMutableDictionary::new();
+    /// dict.insert(ns_string!("key1"), ns_string!("key1"));
+    /// dict.insert(ns_string!("key2"), ns_string!("key2"));
+    /// for (key, obj) in &*dict {
+    ///     assert_eq!(key, obj);
+    /// }
+    /// ```
+    #[cfg(feature = "NSEnumerator")]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, KeyType, ObjectType> {
+        Iter {
+            keys: iter::Iter::new(self),
+            dict: self,
+        }
+    }
+
     /// Iterate over the dictionary's objects / values.
     ///
     /// # Examples
@@ -388,6 +421,34 @@ impl<KeyType: Message, ObjectType: Message> NSMutableDictionary<KeyType, ObjectT
         // SAFETY: The key is copied, and then has the correct type `KeyType`.
         unsafe { self.setObject_forKey(object, key) };
     }
+
+    /// Retains only the entries whose key is contained in `keys`.
+    ///
+    /// Keys are compared using `isEqual:`. Keys in `keys` that are not in
+    /// the dictionary are ignored.
+    ///
+    /// To instead remove the entries for a list of keys, use
+    /// [`removeObjectsForKeys`][Self::removeObjectsForKeys].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray, NSMutableDictionary, NSObject};
+    ///
+    /// let dict = NSMutableDictionary::new();
+    /// dict.insert(ns_string!("a"), &*NSObject::new());
+    /// dict.insert(ns_string!("b"), &*NSObject::new());
+    /// dict.retain_keys(&NSArray::from_slice(&[ns_string!("a")]));
+    /// assert_eq!(dict.len(), 1);
+    /// ```
+    #[cfg(all(feature = "NSArray", feature = "NSObject"))]
+    pub fn retain_keys(&self, keys: &crate::NSArray<KeyType>) {
+        use crate::NSMutableCopying;
+
+        let removed = self.allKeys().mutableCopy();
+        removed.removeObjectsInArray(keys);
+        self.removeObjectsForKeys(&removed);
+    }
 }
 
 #[cfg(feature = "NSEnumerator")]
@@ -480,6 +541,130 @@ __impl_iter! {
     impl<'a, KeyType: Message, ObjectType: Message> Iterator<Item = &'a ObjectType> for ObjectsUnchecked<'a, KeyType, ObjectType> { ... }
 }
 
+/// An iterator over the key-value pairs of a dictionary.
+#[derive(Debug)]
+#[cfg(feature = "NSEnumerator")]
+pub struct Iter<'a, KeyType: Message, ObjectType: Message> {
+    keys: iter::Iter<'a, NSDictionary<KeyType, ObjectType>>,
+    dict: &'a NSDictionary<KeyType, ObjectType>,
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<KeyType: Message, ObjectType: Message> Iterator for Iter<'_, KeyType, ObjectType> {
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+
+    #[inline]
+    #[track_caller]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let key = self.keys.next()?;
+            // The object may only be missing if the dictionary was mutated
+            // since the key was enumerated; the iterator will detect that on
+            // the next call to `next`.
+            if let Some(obj) = self.dict.objectForKey(&key) {
+                return Some((key, obj));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.keys.size_hint().1)
+    }
+}
+
+/// A retained iterator over the key-value pairs of a dictionary.
+#[derive(Debug)]
+#[cfg(feature = "NSEnumerator")]
+pub struct IntoIter<KeyType: Message, ObjectType: Message> {
+    keys: iter::IntoIter<NSDictionary<KeyType, ObjectType>>,
+    dict: Retained<NSDictionary<KeyType, ObjectType>>,
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<KeyType: Message, ObjectType: Message> Iterator for IntoIter<KeyType, ObjectType> {
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+
+    #[inline]
+    #[track_caller]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let key = self.keys.next()?;
+            // Same as in `Iter`.
+            if let Some(obj) = self.dict.objectForKey(&key) {
+                return Some((key, obj));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.keys.size_hint().1)
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<'a, KeyType: Message, ObjectType: Message> IntoIterator
+    for &'a NSDictionary<KeyType, ObjectType>
+{
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+    type IntoIter = Iter<'a, KeyType, ObjectType>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<'a, KeyType: Message, ObjectType: Message> IntoIterator
+    for &'a NSMutableDictionary<KeyType, ObjectType>
+{
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+    type IntoIter = Iter<'a, KeyType, ObjectType>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<KeyType: Message, ObjectType: Message> objc2::rc::RetainedIntoIterator
+    for NSDictionary<KeyType, ObjectType>
+{
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+    type IntoIter = IntoIter<KeyType, ObjectType>;
+
+    #[inline]
+    fn retained_into_iter(this: Retained<Self>) -> Self::IntoIter {
+        IntoIter {
+            dict: this.clone(),
+            keys: iter::IntoIter::new(this),
+        }
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<KeyType: Message, ObjectType: Message> objc2::rc::RetainedIntoIterator
+    for NSMutableDictionary<KeyType, ObjectType>
+{
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+    type IntoIter = IntoIter<KeyType, ObjectType>;
+
+    #[inline]
+    fn retained_into_iter(this: Retained<Self>) -> Self::IntoIter {
+        // SAFETY: Same as `Retained::into_super`, except we avoid the
+        // `'static` bounds, which aren't needed because the superclass
+        // carries the same generics.
+        let dict = unsafe { Retained::cast_unchecked(this.clone()) };
+        IntoIter {
+            dict,
+            keys: iter::IntoIter::new_mutable(this),
+        }
+    }
+}
+
 impl<KeyType: fmt::Debug + Message, ObjectType: fmt::Debug + Message> fmt::Debug
     for NSDictionary<KeyType, ObjectType>
 {
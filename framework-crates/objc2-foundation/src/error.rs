@@ -24,6 +24,54 @@ impl NSError {
         // still good!
         unsafe { Self::initWithDomain_code_userInfo(Self::alloc(), domain, code, None) }
     }
+
+    /// Combine several errors into a single error.
+    ///
+    /// The errors are stored as an `NSArray` in the `userInfo` under
+    /// `NSMultipleUnderlyingErrorsKey`, and the localized description of the
+    /// resulting error is the descriptions of each error, separated by
+    /// newlines.
+    ///
+    /// The error is in the `NSCocoaErrorDomain` domain, and has the
+    /// `NSValidationMultipleErrorsError` code.
+    #[cfg(feature = "FoundationErrors")]
+    #[cfg(all(feature = "NSArray", feature = "NSDictionary", feature = "NSString"))]
+    #[cfg(feature = "NSObject")]
+    #[doc(alias = "NSMultipleUnderlyingErrorsKey")]
+    pub fn aggregate(errors: &[Retained<NSError>]) -> Retained<Self> {
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+        use objc2::runtime::AnyObject;
+        use objc2::AnyThread;
+
+        use crate::{NSArray, NSDictionary, NSString};
+
+        let description = errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let description = NSString::from_str(&description);
+        let errors = NSArray::from_retained_slice(errors);
+
+        let keys: [&NSString; 2] = [Self::NSLocalizedDescriptionKey(), unsafe {
+            crate::NSMultipleUnderlyingErrorsKey
+        }];
+        let objects: [&AnyObject; 2] = [&description, &errors];
+        let user_info = NSDictionary::from_slices(&keys, &objects);
+
+        // SAFETY: The user info dictionary contains an `NSString` for
+        // `NSLocalizedDescriptionKey`, and an `NSArray<NSError>` for
+        // `NSMultipleUnderlyingErrorsKey`, as documented.
+        unsafe {
+            Self::initWithDomain_code_userInfo(
+                Self::alloc(),
+                crate::NSCocoaErrorDomain,
+                crate::NSValidationMultipleErrorsError,
+                Some(&user_info),
+            )
+        }
+    }
 }
 
 /// Accessor methods.
@@ -32,8 +80,82 @@ impl NSError {
     pub fn NSLocalizedDescriptionKey() -> &'static crate::NSErrorUserInfoKey {
         unsafe { crate::NSLocalizedDescriptionKey }
     }
+
+    /// The errors that caused this error.
+    ///
+    /// This contains the error stored under `NSUnderlyingErrorKey` in the
+    /// `userInfo` (if any), followed by the errors stored under
+    /// `NSMultipleUnderlyingErrorsKey`. Objects that are not errors are
+    /// skipped.
+    ///
+    /// This is the same as `-[NSError underlyingErrors]`, but is available on
+    /// all OS versions.
+    ///
+    /// Since `NSError` does not implement [`Error::source`], this is also how
+    /// you walk the chain of errors that caused an error.
+    ///
+    /// [`Error::source`]: std::error::Error::source
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Find the innermost error.
+    ///
+    /// ```
+    /// use objc2::rc::Retained;
+    /// use objc2::Message;
+    /// use objc2_foundation::NSError;
+    ///
+    /// fn root_cause(error: &NSError) -> Retained<NSError> {
+    ///     let mut error = error.retain();
+    ///     while let Some(underlying) = error.underlying_errors().into_iter().next() {
+    ///         error = underlying;
+    ///     }
+    ///     error
+    /// }
+    /// #
+    /// # let error = NSError::new(42, objc2_foundation::ns_string!("MyDomain"));
+    /// # assert_eq!(root_cause(&error), error);
+    /// ```
+    #[cfg(all(feature = "NSArray", feature = "NSDictionary", feature = "NSString"))]
+    #[doc(alias = "underlyingErrors")]
+    #[doc(alias = "NSUnderlyingErrorKey")]
+    #[doc(alias = "NSMultipleUnderlyingErrorsKey")]
+    pub fn underlying_errors(&self) -> alloc::vec::Vec<Retained<NSError>> {
+        use alloc::vec::Vec;
+
+        use crate::NSArray;
+
+        let user_info = self.userInfo();
+        let mut errors = Vec::new();
+
+        if let Some(error) = user_info.objectForKey(unsafe { crate::NSUnderlyingErrorKey }) {
+            errors.extend(error.downcast::<NSError>().ok());
+        }
+
+        let multiple = user_info.objectForKey(unsafe { crate::NSMultipleUnderlyingErrorsKey });
+        if let Some(Ok(multiple)) = multiple.map(|obj| obj.downcast::<NSArray>()) {
+            for i in 0..multiple.count() {
+                errors.extend(multiple.objectAtIndex(i).downcast::<NSError>().ok());
+            }
+        }
+
+        errors
+    }
 }
 
+/// Allows using `NSError` with `?` in functions returning
+/// `Result<T, Box<dyn Error>>`.
+///
+/// `NSError` is immutable, and thus `Send + Sync`, so it can also be
+/// converted to `Box<dyn Error + Send + Sync>`.
+///
+/// Note that [`Error::source`] is not implemented, as that would require
+/// returning a reference to an error stored in the `userInfo` dictionary,
+/// which we cannot guarantee outlives `self`. Use
+/// [`NSError::underlying_errors`] instead.
+///
+/// [`Error::source`]: std::error::Error::source
 #[cfg(feature = "std")]
 impl std::error::Error for NSError {}
 
@@ -92,6 +92,102 @@ impl NSException {
     }
 }
 
+/// Exception catching.
+#[cfg(feature = "exception")]
+impl NSException {
+    /// Run the given closure, and catch any [`NSException`] that it raises.
+    ///
+    /// This is a convenience over [`objc2::exception::catch`], that gives
+    /// access to methods such as [`name`](Self::name) and
+    /// [`reason`](Self::reason) on the caught exception. Exceptions that are
+    /// not instances of `NSException` are thrown again.
+    ///
+    /// Requires the `"exception"` feature.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the exception if one was raised.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the closure panics, or if `nil` was thrown.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSException};
+    ///
+    /// let res = NSException::catch(|| {
+    ///     let reason = ns_string!("something went wrong");
+    ///     NSException::new(ns_string!("MyException"), Some(reason), None)
+    ///         .unwrap()
+    ///         .raise()
+    /// });
+    /// let exception = res.unwrap_err();
+    /// assert_eq!(&*exception.name(), ns_string!("MyException"));
+    /// assert_eq!(&*exception.reason().unwrap(), ns_string!("something went wrong"));
+    /// ```
+    pub fn catch<R>(closure: impl FnOnce() -> R + UnwindSafe) -> Result<R, Retained<Self>> {
+        objc2::exception::catch(closure).map_err(|exception| {
+            let exception = exception.expect("caught nil exception");
+            Self::from_exception(exception).unwrap_or_else(|exception| {
+                objc2::exception::throw(exception);
+            })
+        })
+    }
+}
+
+/// Uncaught exception handling.
+#[cfg(feature = "std")]
+impl NSException {
+    /// Set the function that is called when an exception is not caught,
+    /// right before the program terminates.
+    ///
+    /// This is useful for logging or recording information about the
+    /// exception that caused the crash. The program still terminates after
+    /// the handler has run.
+    ///
+    /// This replaces any previously installed handler, including ones set
+    /// from Objective-C code with `NSSetUncaughtExceptionHandler`.
+    ///
+    /// If the handler panics, the process is aborted, as unwinding out of
+    /// the handler is not supported.
+    #[doc(alias = "NSSetUncaughtExceptionHandler")]
+    pub fn set_uncaught_exception_handler(handler: fn(&NSException)) {
+        use core::ptr::NonNull;
+        use core::sync::atomic::Ordering;
+
+        unsafe extern "C-unwind" fn trampoline(exception: NonNull<NSException>) {
+            let handler = UNCAUGHT_EXCEPTION_HANDLER.load(Ordering::Acquire);
+            // SAFETY: The pointer was created from a `fn(&NSException)`
+            // below, and is always stored before the trampoline is
+            // installed.
+            let handler: fn(&NSException) = unsafe { core::mem::transmute(handler) };
+            // SAFETY: The exception is valid for the duration of the call.
+            let exception = unsafe { exception.as_ref() };
+            // Unwinding into Foundation here would be undefined behaviour.
+            if std::panic::catch_unwind(|| handler(exception)).is_err() {
+                std::process::abort();
+            }
+        }
+
+        UNCAUGHT_EXCEPTION_HANDLER.store(handler as *mut (), Ordering::Release);
+
+        let trampoline: crate::NSUncaughtExceptionHandler = trampoline;
+        // SAFETY: `NSUncaughtExceptionHandler` is translated as a function
+        // pointer, but the argument is declared as a pointer to it; the
+        // function itself expects the function pointer directly.
+        unsafe { crate::NSSetUncaughtExceptionHandler(trampoline as *mut _) };
+    }
+}
+
+#[cfg(feature = "std")]
+static UNCAUGHT_EXCEPTION_HANDLER: core::sync::atomic::AtomicPtr<()> =
+    core::sync::atomic::AtomicPtr::new(core::ptr::null_mut());
+
 impl fmt::Debug for NSException {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let obj: &AnyObject = self.as_ref();
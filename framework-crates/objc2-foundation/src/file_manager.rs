@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+
+use crate::{NSDirectoryEnumerationOptions, NSError, NSFileManager, NSURL};
+
+/// Common file operations.
+///
+/// These are shorthands for the most common file operations, which return
+/// the [`NSError`] describing why the operation failed, instead of just
+/// `nil` or `NO` as some of the underlying methods do.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::{NSFileManager, NSURL};
+///
+/// let manager = NSFileManager::defaultManager();
+/// let dir = std::env::temp_dir().join("objc2-file-manager-example");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// std::fs::write(dir.join("file.txt"), "hello").unwrap();
+/// let url = NSURL::from_file_path(dir.join("file.txt")).unwrap();
+/// assert!(manager.item_exists(&url));
+///
+/// manager.delete_item(&url).unwrap();
+/// assert!(!manager.item_exists(&url));
+/// assert!(manager.delete_item(&url).is_err());
+/// ```
+impl NSFileManager {
+    /// The URLs of the items in the given directory.
+    ///
+    /// This is a shallow listing, the contents of subdirectories are not
+    /// included. The order of the items is unspecified.
+    #[doc(alias = "contentsOfDirectoryAtURL:includingPropertiesForKeys:options:error:")]
+    pub fn list_directory(&self, url: &NSURL) -> Result<Vec<Retained<NSURL>>, Retained<NSError>> {
+        let contents = self.contentsOfDirectoryAtURL_includingPropertiesForKeys_options_error(
+            url,
+            None,
+            NSDirectoryEnumerationOptions::empty(),
+        )?;
+        Ok(contents.to_vec())
+    }
+
+    /// Remove the file or directory at the given URL.
+    ///
+    /// Directories are removed recursively.
+    #[doc(alias = "removeItemAtURL:error:")]
+    pub fn delete_item(&self, url: &NSURL) -> Result<(), Retained<NSError>> {
+        self.removeItemAtURL_error(url)
+    }
+
+    /// Whether a file or directory exists at the given URL.
+    ///
+    /// Symbolic links are followed, so this returns `false` for a broken
+    /// link. Returns `false` if the URL is not a file URL.
+    #[doc(alias = "fileExistsAtPath:")]
+    pub fn item_exists(&self, url: &NSURL) -> bool {
+        url.isFileURL() && url.path().is_some_and(|path| self.fileExistsAtPath(&path))
+    }
+}
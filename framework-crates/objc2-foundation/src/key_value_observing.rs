@@ -0,0 +1,189 @@
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use core::fmt;
+use core::ptr;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{define_class, msg_send, AnyThread, DefinedClass, DowncastTarget, Message};
+
+use crate::{
+    NSCopying, NSDictionary, NSKeyValueChangeKey, NSKeyValueChangeNewKey,
+    NSKeyValueChangeNotificationIsPriorKey, NSKeyValueChangeOldKey, NSKeyValueObservingOptions,
+    NSNumber, NSObject, NSObjectNSKeyValueObserverRegistration, NSObjectProtocol, NSString,
+};
+
+type Handler = Box<dyn Fn(&NSDictionary<NSKeyValueChangeKey, AnyObject>) + Send + Sync>;
+
+define_class!(
+    // SAFETY: The superclass NSObject does not have any subclassing
+    // requirements, and `Observer` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[ivars = Handler]
+    struct Observer;
+
+    impl Observer {
+        #[unsafe(method(observeValueForKeyPath:ofObject:change:context:))]
+        fn observe_value(
+            &self,
+            _key_path: Option<&NSString>,
+            _object: Option<&AnyObject>,
+            change: Option<&NSDictionary<NSKeyValueChangeKey, AnyObject>>,
+            _context: *mut c_void,
+        ) {
+            match change {
+                Some(change) => (self.ivars())(change),
+                None => (self.ivars())(&*NSDictionary::new()),
+            }
+        }
+    }
+
+    unsafe impl NSObjectProtocol for Observer {}
+);
+
+/// A change to an observed property, as passed to the callback given to
+/// [`KvoObserver::observe`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct KvoChange<V> {
+    /// The value before the change.
+    ///
+    /// This is only set if [`NSKeyValueObservingOptions::Old`] was requested,
+    /// and if the value was an object of type `V` (so e.g. `nil` or `NSNull`
+    /// are represented as `None`).
+    pub old: Option<Retained<V>>,
+    /// The value after the change.
+    ///
+    /// This is only set if [`NSKeyValueObservingOptions::New`] was requested
+    /// (and this is not a prior notification), and if the value was an
+    /// object of type `V`.
+    pub new: Option<Retained<V>>,
+    /// Whether this notification was sent before the change happened, see
+    /// [`NSKeyValueObservingOptions::Prior`].
+    pub is_prior: bool,
+}
+
+impl<V: DowncastTarget> KvoChange<V> {
+    fn from_dictionary(change: &NSDictionary<NSKeyValueChangeKey, AnyObject>) -> Self {
+        let get = |key: &NSKeyValueChangeKey| change.objectForKey(key);
+        // SAFETY: The keys are immutable statics.
+        let (old_key, new_key, prior_key) = unsafe {
+            (
+                NSKeyValueChangeOldKey,
+                NSKeyValueChangeNewKey,
+                NSKeyValueChangeNotificationIsPriorKey,
+            )
+        };
+        Self {
+            old: get(old_key).and_then(|old| old.downcast().ok()),
+            new: get(new_key).and_then(|new| new.downcast().ok()),
+            is_prior: get(prior_key)
+                .and_then(|prior| prior.downcast::<NSNumber>().ok())
+                .is_some_and(|prior| prior.as_bool()),
+        }
+    }
+}
+
+/// An active key-value observation, that is removed when dropped.
+///
+/// See the [Key-Value Observing topic][objc2::topics::kvo] for more
+/// information on KVO.
+#[must_use = "if unused, the observation is immediately removed"]
+pub struct KvoObserver {
+    observer: Retained<Observer>,
+    object: Retained<NSObject>,
+    key_path: Retained<NSString>,
+}
+
+impl KvoObserver {
+    /// Observe changes to the value at the given key path on the object.
+    ///
+    /// The callback is invoked synchronously on the thread that modified the
+    /// property, which may be any thread, so it must be [`Send`] and
+    /// [`Sync`].
+    ///
+    /// Values that are not of type `V` are given to the callback as `None`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must be key-value observing compliant for the key path, or
+    /// else an exception is raised (e.g. `NSArray` does not support being
+    /// observed).
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::{ns_string, KvoObserver, NSKeyValueObservingOptions, NSNumber, NSObject};
+    ///
+    /// # let object = NSObject::new();
+    /// let observer = unsafe {
+    ///     KvoObserver::observe::<NSNumber>(
+    ///         &object,
+    ///         ns_string!("myProperty"),
+    ///         NSKeyValueObservingOptions::Old | NSKeyValueObservingOptions::New,
+    ///         |change| println!("changed from {:?} to {:?}", change.old, change.new),
+    ///     )
+    /// };
+    ///
+    /// // Modify `myProperty`, and the callback is invoked.
+    ///
+    /// // Stop observing.
+    /// drop(observer);
+    /// ```
+    #[doc(alias = "addObserver:forKeyPath:options:context:")]
+    pub unsafe fn observe<V: DowncastTarget>(
+        object: &NSObject,
+        key_path: &NSString,
+        options: NSKeyValueObservingOptions,
+        callback: impl Fn(KvoChange<V>) + Send + Sync + 'static,
+    ) -> Self {
+        let handler: Handler = Box::new(
+            move |change: &NSDictionary<NSKeyValueChangeKey, AnyObject>| {
+                callback(KvoChange::from_dictionary(change));
+            },
+        );
+        let observer = Observer::alloc().set_ivars(handler);
+        let observer: Retained<Observer> = unsafe { msg_send![super(observer), init] };
+        let key_path = key_path.copy();
+
+        // SAFETY: The observer is removed again when `KvoObserver` is
+        // dropped, and it is kept alive until then.
+        //
+        // Passing `NULL` as the context is fine, since the observer class is
+        // private, and its superclass (NSObject) is not observing anything.
+        unsafe {
+            object.addObserver_forKeyPath_options_context(
+                &observer,
+                &key_path,
+                options,
+                ptr::null_mut(),
+            );
+        }
+
+        Self {
+            observer,
+            object: object.retain(),
+            key_path,
+        }
+    }
+}
+
+impl Drop for KvoObserver {
+    fn drop(&mut self) {
+        // SAFETY: The observer was registered in `observe`.
+        unsafe {
+            self.object
+                .removeObserver_forKeyPath(&self.observer, &self.key_path);
+        }
+    }
+}
+
+impl fmt::Debug for KvoObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KvoObserver")
+            .field("object", &self.object)
+            .field("key_path", &self.key_path)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,61 @@
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{DowncastTarget, Message};
+
+use crate::{NSData, NSError, NSKeyedArchiver, NSKeyedUnarchiver, NSSecureCoding};
+
+impl NSKeyedArchiver {
+    /// Archive the object using secure coding.
+    ///
+    /// The archive can be decoded again with [`NSKeyedUnarchiver::unarchive`].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSKeyedArchiver, NSKeyedUnarchiver, NSNumber, NSString};
+    ///
+    /// let data = NSKeyedArchiver::archive(ns_string!("hello")).unwrap();
+    ///
+    /// let string = NSKeyedUnarchiver::unarchive::<NSString>(&data).unwrap();
+    /// assert_eq!(string.to_string(), "hello");
+    ///
+    /// // Fails if the archived object is not of the expected class.
+    /// assert!(NSKeyedUnarchiver::unarchive::<NSNumber>(&data).is_err());
+    /// ```
+    #[doc(alias = "archivedDataWithRootObject:requiringSecureCoding:error:")]
+    pub fn archive<T: NSSecureCoding + Message>(
+        object: &T,
+    ) -> Result<Retained<NSData>, Retained<NSError>> {
+        let object: &ProtocolObject<dyn NSSecureCoding> = ProtocolObject::from_ref(object);
+        // SAFETY: The object implements `NSSecureCoding`.
+        unsafe {
+            Self::archivedDataWithRootObject_requiringSecureCoding_error(object.as_ref(), true)
+        }
+    }
+}
+
+impl NSKeyedUnarchiver {
+    /// Unarchive an object of the given type using secure coding.
+    ///
+    /// Returns an error if the data is not a valid archive, or if the root
+    /// object in the archive is not an instance of `T`.
+    ///
+    /// Only `T` is explicitly allowed to be decoded, so the root object
+    /// cannot contain objects of other custom classes. Foundation currently
+    /// allows basic property list types, such as the `NSString` elements of
+    /// an `NSArray`, but logs a warning that this may be disallowed in the
+    /// future.
+    #[doc(alias = "unarchivedObjectOfClass:fromData:error:")]
+    pub fn unarchive<T: NSSecureCoding + DowncastTarget>(
+        data: &NSData,
+    ) -> Result<Retained<T>, Retained<NSError>> {
+        // SAFETY: The class implements `NSSecureCoding`.
+        let object = unsafe { Self::unarchivedObjectOfClass_fromData_error(T::class(), data) }?;
+        // The unarchiver already checks the class of the root object, but
+        // we check it again to avoid relying on that for soundness.
+        Ok(object
+            .downcast::<T>()
+            .expect("unarchived object of the wrong class"))
+    }
+}
@@ -135,8 +135,12 @@ mod comparison_result;
 mod copying;
 #[cfg(feature = "NSData")]
 mod data;
+#[cfg(feature = "NSDate")]
+mod date;
 #[cfg(feature = "NSDecimal")]
 mod decimal;
+#[cfg(all(feature = "NSDecimalNumber", feature = "NSValue"))]
+mod decimal_number;
 #[cfg(feature = "NSDictionary")]
 pub mod dictionary;
 #[cfg(feature = "NSEnumerator")]
@@ -147,17 +151,66 @@ mod error;
 mod exception;
 #[cfg(feature = "NSEnumerator")]
 mod fast_enumeration_state;
+#[cfg(all(
+    feature = "NSArray",
+    feature = "NSError",
+    feature = "NSFileManager",
+    feature = "NSString",
+    feature = "NSURL"
+))]
+mod file_manager;
 mod generated;
 #[cfg(feature = "NSGeometry")]
 mod geometry;
+#[cfg(all(
+    feature = "NSDictionary",
+    feature = "NSKeyValueObserving",
+    feature = "NSObject",
+    feature = "NSString",
+    feature = "NSValue"
+))]
+mod key_value_observing;
+#[cfg(all(
+    feature = "NSData",
+    feature = "NSError",
+    feature = "NSKeyedArchiver",
+    feature = "NSObject"
+))]
+mod keyed_archiver;
 mod macros;
+#[cfg(all(
+    feature = "NSNotification",
+    feature = "NSOperation",
+    feature = "NSString",
+    feature = "block2"
+))]
+mod notification;
 mod ns_consumed;
 #[cfg(feature = "NSValue")]
 mod number;
+#[cfg(feature = "NSOrderedSet")]
+mod ordered_set;
+#[cfg(all(
+    feature = "NSArray",
+    feature = "NSComparisonPredicate",
+    feature = "NSCompoundPredicate",
+    feature = "NSExpression",
+    feature = "NSPredicate",
+    feature = "NSString"
+))]
+mod predicate;
 #[cfg(feature = "NSProcessInfo")]
 mod process_info;
 #[cfg(feature = "NSRange")]
 mod range;
+#[cfg(all(
+    feature = "NSArray",
+    feature = "NSRange",
+    feature = "NSRegularExpression",
+    feature = "NSString",
+    feature = "NSTextCheckingResult"
+))]
+mod regular_expression;
 #[cfg(feature = "NSSet")]
 pub mod set;
 #[cfg(feature = "NSString")]
@@ -170,6 +223,18 @@ mod thread;
 mod to_owned;
 #[cfg(feature = "NSURL")]
 mod url;
+#[cfg(all(feature = "NSString", feature = "NSURL", feature = "NSValue"))]
+mod url_builder;
+#[cfg(all(
+    feature = "async",
+    feature = "NSData",
+    feature = "NSError",
+    feature = "NSURL",
+    feature = "NSURLRequest",
+    feature = "NSURLResponse",
+    feature = "NSURLSession"
+))]
+mod url_session;
 #[cfg(feature = "NSUserDefaults")]
 mod user_defaults;
 mod util;
@@ -178,10 +243,20 @@ mod uuid;
 #[cfg(feature = "NSValue")]
 mod value;
 
+#[cfg(all(
+    feature = "NSAttributedString",
+    feature = "NSDictionary",
+    feature = "NSObject",
+    feature = "NSRange",
+    feature = "NSString"
+))]
+pub use self::attributed_string::AttributedStringBuilder;
 #[cfg(feature = "NSObjCRuntime")]
 pub use self::comparison_result::NSComparisonResult;
 #[cfg(feature = "NSObject")]
 pub use self::copying::{CopyingHelper, MutableCopyingHelper, NSCopying, NSMutableCopying};
+#[cfg(all(feature = "NSData", feature = "std"))]
+pub use self::data::NSDataReader;
 #[cfg(feature = "NSDecimal")]
 pub use self::decimal::NSDecimal;
 #[cfg(feature = "NSEnumerator")]
@@ -192,12 +267,31 @@ pub use self::generated::*;
 pub use self::geometry::NSRectEdge;
 #[cfg(all(feature = "NSGeometry", feature = "objc2-core-foundation"))]
 pub use self::geometry::{NSPoint, NSRect, NSSize};
+#[cfg(all(
+    feature = "NSDictionary",
+    feature = "NSKeyValueObserving",
+    feature = "NSObject",
+    feature = "NSString",
+    feature = "NSValue"
+))]
+pub use self::key_value_observing::{KvoChange, KvoObserver};
+#[cfg(all(
+    feature = "NSNotification",
+    feature = "NSOperation",
+    feature = "NSString",
+    feature = "block2"
+))]
+pub use self::notification::NotificationObserver;
 #[cfg(feature = "NSMapTable")]
 pub use self::ns_consumed::NSFreeMapTable;
+#[cfg(feature = "NSValue")]
+pub use self::number::{NSNumberKind, TryFromNSNumberError};
 #[cfg(feature = "NSRange")]
 pub use self::range::NSRange;
 #[cfg(feature = "NSThread")]
 pub use self::thread::*;
+#[cfg(all(feature = "NSString", feature = "NSURL", feature = "NSValue"))]
+pub use self::url_builder::URLBuilder;
 
 // Available under Foundation, so makes sense here as well:
 // https://developer.apple.com/documentation/foundation/numbers_data_and_basic_values?language=objc
@@ -0,0 +1,93 @@
+use core::ptr::NonNull;
+
+use block2::RcBlock;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, NSObjectProtocol, ProtocolObject};
+use objc2::Message;
+
+use crate::{NSNotification, NSNotificationCenter, NSNotificationName, NSOperationQueue};
+
+/// An observer registered with an [`NSNotificationCenter`], that is removed
+/// again when dropped.
+///
+/// This is created with [`NotificationObserver::add`].
+#[derive(Debug)]
+#[must_use = "if unused, the observer is immediately removed"]
+pub struct NotificationObserver {
+    center: Retained<NSNotificationCenter>,
+    token: Retained<ProtocolObject<dyn NSObjectProtocol>>,
+}
+
+impl NotificationObserver {
+    /// Register a closure to be called with notifications posted to the
+    /// notification center.
+    ///
+    /// If `name` is `None`, the closure receives notifications with any
+    /// name. If `object` is `None`, the closure receives notifications from
+    /// any sender. If `queue` is `None`, the closure is called synchronously
+    /// on the thread that posted the notification.
+    ///
+    /// The closure is called until the returned observer is dropped.
+    ///
+    /// Notifications may be posted from any thread, so the closure must be
+    /// [`Send`] and [`Sync`].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSNotificationCenter, NotificationObserver};
+    ///
+    /// let center = NSNotificationCenter::defaultCenter();
+    /// let name = ns_string!("MyNotification");
+    ///
+    /// let observer = NotificationObserver::add(&center, Some(name), None, None, |notification| {
+    ///     println!("received {}", notification.name());
+    /// });
+    ///
+    /// // SAFETY: The object is `None`.
+    /// unsafe { center.postNotificationName_object(name, None) };
+    ///
+    /// // Stop observing.
+    /// drop(observer);
+    /// ```
+    #[doc(alias = "addObserverForName:object:queue:usingBlock:")]
+    pub fn add<F>(
+        center: &NSNotificationCenter,
+        name: Option<&NSNotificationName>,
+        object: Option<&AnyObject>,
+        queue: Option<&NSOperationQueue>,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(&NSNotification) + Send + Sync + 'static,
+    {
+        let block = RcBlock::new(move |notification: NonNull<NSNotification>| {
+            // SAFETY: The notification is valid for the duration of the
+            // block invocation.
+            handler(unsafe { notification.as_ref() });
+        });
+
+        // SAFETY: The block is `Send + Sync`.
+        //
+        // The object is only used to compare against the sender of posted
+        // notifications, so it can be of any type.
+        let token = unsafe {
+            center.addObserverForName_object_queue_usingBlock(name, object, queue, &block)
+        };
+
+        Self {
+            center: center.retain(),
+            token,
+        }
+    }
+}
+
+impl Drop for NotificationObserver {
+    fn drop(&mut self) {
+        let token: &AnyObject = (*self.token).as_ref();
+        // SAFETY: The token was returned from `addObserverForName:...`
+        // on this notification center.
+        unsafe { self.center.removeObserver(token) }
+    }
+}
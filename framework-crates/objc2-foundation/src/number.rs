@@ -89,6 +89,12 @@ macro_rules! def_get_fn {
 }
 
 /// Getter methods.
+///
+/// These never fail, but instead coerce the stored value to the requested
+/// type the same way Foundation does, which may be lossy. E.g. floating
+/// point values are truncated towards zero when retrieved as an integer,
+/// and large integers may lose precision when retrieved as a float. Use
+/// [`NSNumber::encoding`] if you need to know the stored type.
 impl NSNumber {
     def_get_fn! {
         (as_bool -> bool; boolValue),
@@ -209,8 +215,343 @@ impl NSNumber {
             _ => unreachable!("invalid encoding for NSNumber"),
         }
     }
+
+    /// Whether the number stores an integer.
+    ///
+    /// Note that numbers created from [`bool`] are stored as integers, so
+    /// this also returns `true` for those. Use [`is_boolean`] if you need to
+    /// distinguish them.
+    ///
+    /// [`is_boolean`]: Self::is_boolean
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSNumber;
+    ///
+    /// assert!(NSNumber::new_i32(42).is_integer());
+    /// assert!(!NSNumber::new_f64(4.2).is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        !self.is_float()
+    }
+
+    /// Whether the number stores a floating point value.
+    #[doc(alias = "objCType")]
+    pub fn is_float(&self) -> bool {
+        matches!(self.encoding(), Encoding::Float | Encoding::Double)
+    }
+
+    /// Whether the number is one of the boolean singletons, as created by
+    /// [`NSNumber::new_bool`] (or `@YES` / `@NO` in Objective-C).
+    ///
+    /// Due to limitations in Objective-C type encodings, it is not possible
+    /// to distinguish a boolean from an `i8` by the encoding, so this instead
+    /// compares the number to the singleton objects.
+    #[doc(alias = "kCFBooleanTrue")]
+    #[doc(alias = "kCFBooleanFalse")]
+    pub fn is_boolean(&self) -> bool {
+        let ptr: *const Self = self;
+        [true, false]
+            .iter()
+            .any(|&value| Retained::as_ptr(&Self::new_bool(value)) == ptr)
+    }
+
+    /// The kind of value stored in the number.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSNumber, NSNumberKind};
+    ///
+    /// assert_eq!(NSNumber::new_i32(-42).kind(), NSNumberKind::Signed);
+    /// assert_eq!(NSNumber::new_f32(4.2).kind(), NSNumberKind::Float);
+    /// assert_eq!(NSNumber::new_bool(true).kind(), NSNumberKind::Bool);
+    /// ```
+    #[doc(alias = "objCType")]
+    pub fn kind(&self) -> NSNumberKind {
+        if self.is_boolean() {
+            return NSNumberKind::Bool;
+        }
+        match self.encoding() {
+            Encoding::Char
+            | Encoding::Short
+            | Encoding::Int
+            | Encoding::Long
+            | Encoding::LongLong => NSNumberKind::Signed,
+            Encoding::UChar
+            | Encoding::UShort
+            | Encoding::UInt
+            | Encoding::ULong
+            | Encoding::ULongLong => NSNumberKind::Unsigned,
+            Encoding::Float | Encoding::Double => NSNumberKind::Float,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The kind of value stored in an [`NSNumber`].
+///
+/// This is returned from [`NSNumber::kind`].
+///
+/// More kinds may be added in the future.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NSNumberKind {
+    /// A boolean, as created by [`NSNumber::new_bool`].
+    Bool,
+    /// A signed integer.
+    Signed,
+    /// An unsigned integer.
+    Unsigned,
+    /// A floating point value.
+    Float,
+}
+
+/// The error returned when a checked conversion from [`NSNumber`] fails.
+///
+/// This happens when the stored value cannot be represented exactly in the
+/// requested type, see the [`TryFrom`] implementations on `NSNumber`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TryFromNSNumberError {
+    kind: NSNumberKind,
+}
+
+impl fmt::Display for TryFromNSNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            NSNumberKind::Bool => "boolean",
+            NSNumberKind::Signed => "signed integer",
+            NSNumberKind::Unsigned => "unsigned integer",
+            NSNumberKind::Float => "floating point value",
+        };
+        write!(f, "stored {kind} cannot be represented in the target type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromNSNumberError {}
+
+// 2^63 and 2^64 are exactly representable as `f64`, while `i64::MAX` and
+// `u64::MAX` are not.
+const I64_BOUND: f64 = 9_223_372_036_854_775_808.0;
+const U64_BOUND: f64 = 18_446_744_073_709_551_616.0;
+
+/// Fails if the number is a boolean, a negative value that doesn't fit, or
+/// a floating point value that isn't integral.
+impl TryFrom<&NSNumber> for i64 {
+    type Error = TryFromNSNumberError;
+
+    fn try_from(number: &NSNumber) -> Result<Self, Self::Error> {
+        let kind = number.kind();
+        let res = match kind {
+            NSNumberKind::Bool => None,
+            NSNumberKind::Signed => Some(number.as_i64()),
+            NSNumberKind::Unsigned => i64::try_from(number.as_u64()).ok(),
+            NSNumberKind::Float => {
+                let val = number.as_f64();
+                (val.trunc() == val && -I64_BOUND <= val && val < I64_BOUND).then_some(val as i64)
+            }
+        };
+        res.ok_or(TryFromNSNumberError { kind })
+    }
+}
+
+/// Fails if the number is a boolean, negative, or a floating point value
+/// that isn't integral.
+impl TryFrom<&NSNumber> for u64 {
+    type Error = TryFromNSNumberError;
+
+    fn try_from(number: &NSNumber) -> Result<Self, Self::Error> {
+        let kind = number.kind();
+        let res = match kind {
+            NSNumberKind::Bool => None,
+            NSNumberKind::Signed => u64::try_from(number.as_i64()).ok(),
+            NSNumberKind::Unsigned => Some(number.as_u64()),
+            NSNumberKind::Float => {
+                let val = number.as_f64();
+                (val.trunc() == val && 0.0 <= val && val < U64_BOUND).then_some(val as u64)
+            }
+        };
+        res.ok_or(TryFromNSNumberError { kind })
+    }
+}
+
+/// Fails if the number is a boolean, or an integer that cannot be
+/// represented exactly as a floating point value.
+impl TryFrom<&NSNumber> for f64 {
+    type Error = TryFromNSNumberError;
+
+    fn try_from(number: &NSNumber) -> Result<Self, Self::Error> {
+        let kind = number.kind();
+        let res = match kind {
+            NSNumberKind::Bool => None,
+            NSNumberKind::Signed => {
+                let val = number.as_i64();
+                let float = val as f64;
+                (float < I64_BOUND && float as i64 == val).then_some(float)
+            }
+            NSNumberKind::Unsigned => {
+                let val = number.as_u64();
+                let float = val as f64;
+                (float < U64_BOUND && float as u64 == val).then_some(float)
+            }
+            // Widening `f32` to `f64` is lossless.
+            NSNumberKind::Float => Some(number.as_f64()),
+        };
+        res.ok_or(TryFromNSNumberError { kind })
+    }
 }
 
+/// Fails if the number is not a boolean, see [`NSNumber::is_boolean`].
+impl TryFrom<&NSNumber> for bool {
+    type Error = TryFromNSNumberError;
+
+    fn try_from(number: &NSNumber) -> Result<Self, Self::Error> {
+        match number.kind() {
+            NSNumberKind::Bool => Ok(number.as_bool()),
+            kind => Err(TryFromNSNumberError { kind }),
+        }
+    }
+}
+
+/// The value of a number, as used in arithmetic.
+#[derive(Clone, Copy)]
+enum Value {
+    // Wide enough to hold both `i64` and `u64`, and any sum, difference or
+    // quotient of those (though not all products).
+    Int(i128),
+    Float(f64),
+}
+
+impl Value {
+    fn new(number: &NSNumber) -> Self {
+        match number.kind() {
+            NSNumberKind::Bool => Self::Int(number.as_bool() as i128),
+            NSNumberKind::Signed => Self::Int(number.as_i64() as i128),
+            NSNumberKind::Unsigned => Self::Int(number.as_u64() as i128),
+            NSNumberKind::Float => Self::Float(number.as_f64()),
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(val) => val as f64,
+            Self::Float(val) => val,
+        }
+    }
+}
+
+fn int_to_number(val: i128) -> Option<Retained<NSNumber>> {
+    if let Ok(val) = i64::try_from(val) {
+        Some(NSNumber::new_i64(val))
+    } else {
+        u64::try_from(val).ok().map(NSNumber::new_u64)
+    }
+}
+
+macro_rules! impl_op {
+    ($(#[$m:meta])* $trait:ident, $method:ident, $checked:ident, $msg:literal) => {
+        $(#[$m])*
+        impl core::ops::$trait<&NSNumber> for &NSNumber {
+            type Output = Retained<NSNumber>;
+
+            fn $method(self, rhs: &NSNumber) -> Retained<NSNumber> {
+                match (Value::new(self), Value::new(rhs)) {
+                    (Value::Int(lhs), Value::Int(rhs)) => {
+                        lhs.$checked(rhs).and_then(int_to_number).expect($msg)
+                    }
+                    (lhs, rhs) => {
+                        NSNumber::new_f64(core::ops::$trait::$method(lhs.as_f64(), rhs.as_f64()))
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_op!(
+    /// Add two numbers.
+    ///
+    /// If both numbers are integers (or booleans), the result is an integer,
+    /// stored as `i64` if it fits, and as `u64` otherwise. If either number
+    /// is a floating point value, the result is an `f64`.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result of adding two integers does not fit in an `i64`
+    /// nor an `u64`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSNumber;
+    ///
+    /// let sum = &*NSNumber::new_i32(2) + &*NSNumber::new_u8(3);
+    /// assert_eq!(sum.as_i64(), 5);
+    ///
+    /// let sum = &*NSNumber::new_i32(2) + &*NSNumber::new_f32(0.5);
+    /// assert_eq!(sum.as_f64(), 2.5);
+    /// ```
+    Add,
+    add,
+    checked_add,
+    "attempt to add with overflow"
+);
+
+impl_op!(
+    /// Subtract two numbers.
+    ///
+    /// See [`Add`][core::ops::Add] for how the result is stored.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result of subtracting two integers does not fit in an
+    /// `i64` nor an `u64`.
+    Sub,
+    sub,
+    checked_sub,
+    "attempt to subtract with overflow"
+);
+
+impl_op!(
+    /// Multiply two numbers.
+    ///
+    /// See [`Add`][core::ops::Add] for how the result is stored.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result of multiplying two integers does not fit in an
+    /// `i64` nor an `u64`.
+    Mul,
+    mul,
+    checked_mul,
+    "attempt to multiply with overflow"
+);
+
+impl_op!(
+    /// Divide two numbers.
+    ///
+    /// Division of two integers rounds towards zero, like for Rust's integer
+    /// types. See [`Add`][core::ops::Add] for how the result is stored.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if dividing an integer by zero. Dividing a floating point
+    /// value by zero results in an infinity or NaN.
+    Div,
+    div,
+    checked_div,
+    "attempt to divide by zero"
+);
+
 impl hash::Hash for NSNumber {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
@@ -0,0 +1,31 @@
+//! Utilities for the `NSOrderedSet` and `NSMutableOrderedSet` classes.
+use objc2::Message;
+
+use crate::{util, NSMutableOrderedSet};
+
+/// Convenience mutation methods.
+impl<ObjectType: Message> NSMutableOrderedSet<ObjectType> {
+    /// Insert an object into a sorted ordered set, keeping the set sorted.
+    ///
+    /// The set must already be sorted according to `compare`, otherwise the
+    /// position at which the object is inserted is unspecified. The position
+    /// is found with a binary search.
+    ///
+    /// Returns the index at which the object was inserted, or `None` if the
+    /// set already contained an equal object (as determined by `isEqual:`),
+    /// in which case the set is left unchanged.
+    pub fn insert_sorted_by<F: FnMut(&ObjectType, &ObjectType) -> core::cmp::Ordering>(
+        &self,
+        obj: &ObjectType,
+        mut compare: F,
+    ) -> Option<usize> {
+        if self.containsObject(obj) {
+            return None;
+        }
+        let index = util::partition_point(self.count(), |i| {
+            compare(&self.objectAtIndex(i), obj) != core::cmp::Ordering::Greater
+        });
+        self.insertObject_atIndex(obj, index);
+        Some(index)
+    }
+}
@@ -0,0 +1,138 @@
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, NSObject};
+use objc2::Message;
+
+use crate::{
+    NSArray, NSComparisonPredicate, NSComparisonPredicateModifier, NSComparisonPredicateOptions,
+    NSCompoundPredicate, NSExpression, NSPredicate, NSPredicateOperatorType, NSString,
+};
+
+fn compare(
+    key: &NSString,
+    value: &AnyObject,
+    operator: NSPredicateOperatorType,
+) -> Retained<NSPredicate> {
+    let lhs = NSExpression::expressionForKeyPath(key);
+    // SAFETY: The value is a valid object. Comparing it with an object of
+    // an incompatible type raises an exception when evaluated, which is
+    // safe.
+    let rhs = unsafe { NSExpression::expressionForConstantValue(Some(value)) };
+    let predicate =
+        NSComparisonPredicate::predicateWithLeftExpression_rightExpression_modifier_type_options(
+            &lhs,
+            &rhs,
+            NSComparisonPredicateModifier::DirectPredicateModifier,
+            operator,
+            NSComparisonPredicateOptions::empty(),
+        );
+    predicate.into_super()
+}
+
+/// Building predicates.
+///
+/// These construct `NSComparisonPredicate` and `NSCompoundPredicate`
+/// objects directly, instead of parsing a format string, so there is no
+/// need to worry about quoting or escaping the keys and values.
+///
+/// The key is a key path, that is looked up on the evaluated object with
+/// key-value coding. Evaluating a predicate on an object that does not have
+/// the key, or comparing values of incompatible types, raises an exception.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::{ns_string, NSArray, NSNumber, NSPredicate};
+///
+/// let predicate = NSPredicate::and(&[
+///     &NSPredicate::key_greater_than(ns_string!("self"), &NSNumber::new_i32(2)),
+///     &NSPredicate::not(&NSPredicate::key_equals(ns_string!("self"), &NSNumber::new_i32(4))),
+/// ]);
+///
+/// let numbers: Vec<_> = (1..6).map(NSNumber::new_i32).collect();
+/// let array = NSArray::from_retained_slice(&numbers);
+/// let filtered = array.filteredArrayUsingPredicate(&predicate);
+/// assert_eq!(filtered.to_vec(), [NSNumber::new_i32(3), NSNumber::new_i32(5)]);
+/// ```
+impl NSPredicate {
+    /// A predicate that matches if the value for the key is equal to the
+    /// given value.
+    #[doc(alias = "NSEqualToPredicateOperatorType")]
+    pub fn key_equals(key: &NSString, value: &NSObject) -> Retained<Self> {
+        compare(
+            key,
+            value,
+            NSPredicateOperatorType::EqualToPredicateOperatorType,
+        )
+    }
+
+    /// A predicate that matches if the value for the key is greater than the
+    /// given value.
+    #[doc(alias = "NSGreaterThanPredicateOperatorType")]
+    pub fn key_greater_than(key: &NSString, value: &NSObject) -> Retained<Self> {
+        compare(
+            key,
+            value,
+            NSPredicateOperatorType::GreaterThanPredicateOperatorType,
+        )
+    }
+
+    /// A predicate that matches if the value for the key is less than the
+    /// given value.
+    #[doc(alias = "NSLessThanPredicateOperatorType")]
+    pub fn key_less_than(key: &NSString, value: &NSObject) -> Retained<Self> {
+        compare(
+            key,
+            value,
+            NSPredicateOperatorType::LessThanPredicateOperatorType,
+        )
+    }
+
+    /// A predicate that matches if the string value for the key contains
+    /// the given substring.
+    ///
+    /// The comparison is case-sensitive.
+    #[doc(alias = "NSContainsPredicateOperatorType")]
+    pub fn key_contains(key: &NSString, substring: &NSString) -> Retained<Self> {
+        compare(
+            key,
+            substring,
+            NSPredicateOperatorType::ContainsPredicateOperatorType,
+        )
+    }
+
+    /// A predicate that matches if the value for the key is equal to one of
+    /// the values in the array.
+    #[doc(alias = "NSInPredicateOperatorType")]
+    pub fn key_in<T: Message>(key: &NSString, values: &NSArray<T>) -> Retained<Self> {
+        compare(
+            key,
+            values,
+            NSPredicateOperatorType::InPredicateOperatorType,
+        )
+    }
+
+    /// A predicate that matches if all of the given predicates match.
+    ///
+    /// Matches everything if `predicates` is empty.
+    #[doc(alias = "andPredicateWithSubpredicates:")]
+    pub fn and(predicates: &[&NSPredicate]) -> Retained<Self> {
+        NSCompoundPredicate::andPredicateWithSubpredicates(&NSArray::from_slice(predicates))
+            .into_super()
+    }
+
+    /// A predicate that matches if any of the given predicates match.
+    ///
+    /// Matches nothing if `predicates` is empty.
+    #[doc(alias = "orPredicateWithSubpredicates:")]
+    pub fn or(predicates: &[&NSPredicate]) -> Retained<Self> {
+        NSCompoundPredicate::orPredicateWithSubpredicates(&NSArray::from_slice(predicates))
+            .into_super()
+    }
+
+    /// A predicate that matches if the given predicate does not match.
+    #[doc(alias = "notPredicateWithSubpredicate:")]
+    pub fn not(predicate: &NSPredicate) -> Retained<Self> {
+        NSCompoundPredicate::notPredicateWithSubpredicate(predicate).into_super()
+    }
+}
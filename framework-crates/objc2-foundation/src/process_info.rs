@@ -1,11 +1,42 @@
 use core::fmt;
 use core::panic::{RefUnwindSafe, UnwindSafe};
+#[cfg(feature = "NSDate")]
+use core::time::Duration;
 
 use crate::NSProcessInfo;
 
 impl UnwindSafe for NSProcessInfo {}
 impl RefUnwindSafe for NSProcessInfo {}
 
+/// System information.
+impl NSProcessInfo {
+    /// The amount of time the system has been awake since it was last
+    /// restarted.
+    #[doc(alias = "systemUptime")]
+    #[cfg(feature = "NSDate")]
+    pub fn system_uptime(&self) -> Duration {
+        Duration::from_secs_f64(self.systemUptime())
+    }
+
+    /// The amount of physical memory on the computer, in bytes.
+    #[doc(alias = "physicalMemory")]
+    pub fn physical_memory(&self) -> u64 {
+        self.physicalMemory()
+    }
+
+    /// The number of processing cores available on the computer.
+    #[doc(alias = "processorCount")]
+    pub fn processor_count(&self) -> usize {
+        self.processorCount()
+    }
+
+    /// The number of active processing cores available on the computer.
+    #[doc(alias = "activeProcessorCount")]
+    pub fn active_processor_count(&self) -> usize {
+        self.activeProcessorCount()
+    }
+}
+
 impl fmt::Debug for NSProcessInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut debug = f.debug_struct("NSProcessInfo");
@@ -1,3 +1,4 @@
+use core::cmp;
 use core::ops::Range;
 
 use objc2::encode::{Encode, Encoding, RefEncode};
@@ -83,11 +84,89 @@ impl NSRange {
             .expect("NSRange too large")
     }
 
+    /// Returns `true` if the range's location is `NSNotFound`.
+    ///
+    /// This is what many Foundation methods return when they fail to find
+    /// something, so you will usually want to check this before converting
+    /// the range to a [`Range<usize>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSNotFound, NSRange};
+    ///
+    /// assert!(NSRange::new(NSNotFound as usize, 0).is_not_found());
+    /// assert!(!NSRange::from(3..5).is_not_found());
+    /// ```
+    #[inline]
+    pub fn is_not_found(&self) -> bool {
+        self.location == NOT_FOUND
+    }
+
+    /// Returns the range that is contained in both `self` and `other`, or
+    /// `None` if they don't overlap.
+    ///
+    /// Ranges whose location is `NSNotFound` never overlap with anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// let range = NSRange::from(3..7);
+    /// assert_eq!(range.intersection(NSRange::from(5..10)), Some(NSRange::from(5..7)));
+    /// assert_eq!(range.intersection(NSRange::from(7..10)), None);
+    /// ```
+    #[inline]
+    #[doc(alias = "NSIntersectionRange")]
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        if self.is_not_found() || other.is_not_found() {
+            return None;
+        }
+        let start = cmp::max(self.location, other.location);
+        let end = cmp::min(self.end(), other.end());
+        if start < end {
+            Some(Self::from(start..end))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest range that contains both `self` and `other`.
+    ///
+    /// If the location of one of the ranges is `NSNotFound`, the other range
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// let range = NSRange::from(3..7);
+    /// assert_eq!(range.union(NSRange::from(5..10)), NSRange::from(3..10));
+    /// assert_eq!(range.union(NSRange::from(9..10)), NSRange::from(3..10));
+    /// ```
+    #[inline]
+    #[doc(alias = "NSUnionRange")]
+    pub fn union(&self, other: Self) -> Self {
+        if self.is_not_found() {
+            return other;
+        }
+        if other.is_not_found() {
+            return *self;
+        }
+        let start = cmp::min(self.location, other.location);
+        let end = cmp::max(self.end(), other.end());
+        Self::from(start..end)
+    }
+
     // TODO: https://developer.apple.com/documentation/foundation/1408420-nsrangefromstring
-    // TODO: NSUnionRange
-    // TODO: NSIntersectionRange
 }
 
+/// The value of `NSNotFound`, available here without the `"NSObjCRuntime"`
+/// feature.
+const NOT_FOUND: NSUInteger = crate::NSInteger::MAX as NSUInteger;
+
 // Sadly, we can't do this:
 // impl RangeBounds<usize> for NSRange {
 //     fn start_bound(&self) -> Bound<&usize> {
@@ -111,6 +190,11 @@ impl From<Range<usize>> for NSRange {
     }
 }
 
+/// This does not check for `NSNotFound`, see [`NSRange::is_not_found`].
+///
+/// # Panics
+///
+/// Panics if the end of the range overflows, see [`NSRange::end`].
 impl From<NSRange> for Range<usize> {
     #[inline]
     fn from(nsrange: NSRange) -> Self {
@@ -165,6 +249,32 @@ mod tests {
         assert!(!range.contains(21));
     }
 
+    #[test]
+    fn test_empty() {
+        let range = NSRange::from(10..10);
+        assert!(range.is_empty());
+        assert!(!range.contains(10));
+        assert_eq!(range.intersection(NSRange::from(0..20)), None);
+        assert_eq!(range.union(NSRange::from(12..14)), NSRange::from(10..14));
+        assert_eq!(Range::from(range), 10..10);
+    }
+
+    #[test]
+    fn test_not_found() {
+        let range = NSRange::new(NOT_FOUND, 0);
+        assert!(range.is_not_found());
+        assert!(!range.contains(NOT_FOUND));
+        assert_eq!(Range::from(range), NOT_FOUND..NOT_FOUND);
+
+        // A length is allowed, and doesn't overflow.
+        let range = NSRange::new(NOT_FOUND, 1);
+        assert!(range.is_not_found());
+        assert!(range.contains(NOT_FOUND));
+        assert_eq!(range.intersection(range), None);
+
+        assert!(!NSRange::from(0..NOT_FOUND).is_not_found());
+    }
+
     #[test]
     fn test_end() {
         let range = NSRange::from(10..20);
@@ -176,6 +286,43 @@ mod tests {
         assert!(!range.contains(21));
     }
 
+    #[test]
+    fn test_intersection() {
+        let range = NSRange::from(10..20);
+        assert_eq!(range.intersection(range), Some(range));
+        assert_eq!(
+            range.intersection(NSRange::from(15..25)),
+            Some(NSRange::from(15..20))
+        );
+        assert_eq!(
+            range.intersection(NSRange::from(0..15)),
+            Some(NSRange::from(10..15))
+        );
+        assert_eq!(
+            range.intersection(NSRange::from(12..14)),
+            Some(NSRange::from(12..14))
+        );
+        assert_eq!(range.intersection(NSRange::from(0..10)), None);
+        assert_eq!(range.intersection(NSRange::from(20..30)), None);
+        assert_eq!(range.intersection(NSRange::from(25..30)), None);
+        assert_eq!(range.intersection(NSRange::from(15..15)), None);
+        assert_eq!(range.intersection(NSRange::new(NOT_FOUND, 0)), None);
+        assert_eq!(NSRange::new(NOT_FOUND, 0).intersection(range), None);
+    }
+
+    #[test]
+    fn test_union() {
+        let range = NSRange::from(10..20);
+        assert_eq!(range.union(range), range);
+        assert_eq!(range.union(NSRange::from(15..25)), NSRange::from(10..25));
+        assert_eq!(range.union(NSRange::from(0..15)), NSRange::from(0..20));
+        assert_eq!(range.union(NSRange::from(12..14)), range);
+        assert_eq!(range.union(NSRange::from(25..30)), NSRange::from(10..30));
+        assert_eq!(range.union(NSRange::from(0..5)), NSRange::from(0..20));
+        assert_eq!(range.union(NSRange::new(NOT_FOUND, 0)), range);
+        assert_eq!(NSRange::new(NOT_FOUND, 0).union(range), range);
+    }
+
     #[test]
     #[should_panic = "NSRange too large"]
     fn test_end_large() {
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+
+use crate::{NSMatchingOptions, NSRange, NSRegularExpression, NSString, NSTextCheckingResult};
+
+fn full_range(string: &NSString) -> NSRange {
+    NSRange::new(0, string.length())
+}
+
+fn captures(result: &NSTextCheckingResult, string: &NSString) -> Vec<Option<Retained<NSString>>> {
+    (0..result.numberOfRanges())
+        .map(|i| {
+            let range = result.rangeAtIndex(i);
+            // Groups that did not participate in the match have their
+            // location set to `NSNotFound`.
+            (!range.is_not_found()).then(|| string.substringWithRange(range))
+        })
+        .collect()
+}
+
+/// Convenience methods for matching against a whole string.
+///
+/// Capture groups are returned by index, where index `0` is the entire
+/// match, and the following indices are the capture groups in the order that
+/// their opening parenthesis appear in the pattern (including named groups).
+/// Groups that did not participate in the match are `None`.
+///
+/// The captures are returned as new strings, since it is not possible to
+/// borrow UTF-8 string slices from an `NSString` (which is usually stored as
+/// UTF-16). The conversion between the UTF-16 ranges that
+/// `NSRegularExpression` uses and the returned strings is handled
+/// internally.
+impl NSRegularExpression {
+    /// Whether the regular expression matches anywhere in the string.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSRegularExpression, NSRegularExpressionOptions};
+    ///
+    /// let regex = NSRegularExpression::regularExpressionWithPattern_options_error(
+    ///     ns_string!(r"\d+"),
+    ///     NSRegularExpressionOptions::empty(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(regex.is_match(ns_string!("abc 123")));
+    /// assert!(!regex.is_match(ns_string!("abc")));
+    /// ```
+    #[doc(alias = "firstMatchInString:options:range:")]
+    pub fn is_match(&self, string: &NSString) -> bool {
+        self.firstMatchInString_options_range(
+            string,
+            NSMatchingOptions::empty(),
+            full_range(string),
+        )
+        .is_some()
+    }
+
+    /// The capture groups of the first match in the string, or `None` if
+    /// the regular expression does not match.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSRegularExpression, NSRegularExpressionOptions};
+    ///
+    /// let regex = NSRegularExpression::regularExpressionWithPattern_options_error(
+    ///     ns_string!(r"(\w+)@(\w+)(\.com)?"),
+    ///     NSRegularExpressionOptions::empty(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let captures = regex.first_match_captures(ns_string!("mail: user@example")).unwrap();
+    /// let captures: Vec<_> = captures.iter().map(|c| c.as_ref().map(|c| c.to_string())).collect();
+    /// assert_eq!(
+    ///     captures,
+    ///     [Some("user@example".into()), Some("user".into()), Some("example".into()), None],
+    /// );
+    /// ```
+    #[doc(alias = "firstMatchInString:options:range:")]
+    pub fn first_match_captures(
+        &self,
+        string: &NSString,
+    ) -> Option<Vec<Option<Retained<NSString>>>> {
+        let result = self.firstMatchInString_options_range(
+            string,
+            NSMatchingOptions::empty(),
+            full_range(string),
+        )?;
+        Some(captures(&result, string))
+    }
+
+    /// The capture groups of every (non-overlapping) match in the string.
+    #[doc(alias = "matchesInString:options:range:")]
+    pub fn all_captures(&self, string: &NSString) -> Vec<Vec<Option<Retained<NSString>>>> {
+        self.matchesInString_options_range(string, NSMatchingOptions::empty(), full_range(string))
+            .to_vec()
+            .iter()
+            .map(|result| captures(result, string))
+            .collect()
+    }
+}
@@ -2,6 +2,8 @@
 use alloc::vec::Vec;
 #[cfg(feature = "NSEnumerator")]
 use core::fmt;
+#[cfg(all(feature = "std", feature = "NSEnumerator"))]
+use core::hash;
 
 use objc2::rc::{Retained, RetainedFromIterator};
 use objc2::{msg_send, AnyThread, Message};
@@ -193,6 +195,141 @@ impl<ObjectType: Message> NSSet<ObjectType> {
     pub fn to_vec(&self) -> Vec<Retained<ObjectType>> {
         self.iter().collect()
     }
+
+    /// Returns a [`HashSet`] containing the set's elements.
+    ///
+    /// The elements are retained, so the returned set stays valid even if
+    /// `self` is later mutated.
+    ///
+    /// To go the other way, collect the elements into an `NSSet` (this works
+    /// with any iterator, not just a [`HashSet`]).
+    ///
+    /// [`HashSet`]: std::collections::HashSet
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    ///
+    /// use objc2::rc::Retained;
+    /// use objc2_foundation::{NSSet, NSString};
+    ///
+    /// let set = NSSet::from_retained_slice(&[NSString::from_str("a"), NSString::from_str("b")]);
+    /// let hash_set = set.to_hash_set();
+    /// assert!(hash_set.contains(&*NSString::from_str("a")));
+    ///
+    /// let set: Retained<NSSet<NSString>> = hash_set.into_iter().collect();
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg(feature = "NSEnumerator")]
+    pub fn to_hash_set(&self) -> std::collections::HashSet<Retained<ObjectType>>
+    where
+        ObjectType: hash::Hash + Eq,
+    {
+        self.iter().collect()
+    }
+}
+
+/// Set algebra.
+impl<ObjectType: Message> NSSet<ObjectType> {
+    /// Returns a new set with the elements that are in `self`, in `other`,
+    /// or in both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSSet};
+    ///
+    /// let set1 = NSSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    /// let set2 = NSSet::from_slice(&[ns_string!("two"), ns_string!("three")]);
+    /// assert_eq!(set1.union(&set2).len(), 3);
+    /// ```
+    #[doc(alias = "setByAddingObjectsFromSet:")]
+    pub fn union(&self, other: &NSSet<ObjectType>) -> Retained<NSSet<ObjectType>> {
+        self.setByAddingObjectsFromSet(other)
+    }
+
+    /// Returns a new set with the elements that are in both `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSSet};
+    ///
+    /// let set1 = NSSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    /// let set2 = NSSet::from_slice(&[ns_string!("two"), ns_string!("three")]);
+    /// assert_eq!(set1.intersection(&set2).len(), 1);
+    /// ```
+    #[doc(alias = "intersectSet:")]
+    pub fn intersection(&self, other: &NSSet<ObjectType>) -> Retained<NSSet<ObjectType>> {
+        let set = self.to_mutable();
+        set.intersectSet(other);
+        Self::freeze(set)
+    }
+
+    /// Returns a new set with the elements that are in `self`, but not in
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSSet};
+    ///
+    /// let set1 = NSSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    /// let set2 = NSSet::from_slice(&[ns_string!("two"), ns_string!("three")]);
+    /// assert_eq!(set1.difference(&set2).len(), 1);
+    /// ```
+    #[doc(alias = "minusSet:")]
+    pub fn difference(&self, other: &NSSet<ObjectType>) -> Retained<NSSet<ObjectType>> {
+        let set = self.to_mutable();
+        set.minusSet(other);
+        Self::freeze(set)
+    }
+
+    /// Whether every element in `self` is also in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSSet};
+    ///
+    /// let set1 = NSSet::from_slice(&[ns_string!("one")]);
+    /// let set2 = NSSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    /// assert!(set1.is_subset(&set2));
+    /// assert!(!set2.is_subset(&set1));
+    /// ```
+    #[doc(alias = "isSubsetOfSet:")]
+    pub fn is_subset(&self, other: &NSSet<ObjectType>) -> bool {
+        self.isSubsetOfSet(other)
+    }
+
+    fn to_mutable(&self) -> Retained<NSMutableSet<ObjectType>> {
+        NSMutableSet::initWithSet(NSMutableSet::alloc(), self)
+    }
+
+    fn freeze(set: Retained<NSMutableSet<ObjectType>>) -> Retained<NSSet<ObjectType>> {
+        // SAFETY: Same as `Retained::into_super`, except we avoid the
+        // `'static` bounds, which aren't needed because the superclass
+        // carries the same generics.
+        //
+        // The set is not mutated after this, since we had the only
+        // reference to it.
+        unsafe { Retained::cast_unchecked(set) }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "NSEnumerator")]
+impl<ObjectType: Message + hash::Hash + Eq> From<&NSSet<ObjectType>>
+    for std::collections::HashSet<Retained<ObjectType>>
+{
+    #[inline]
+    fn from(set: &NSSet<ObjectType>) -> Self {
+        set.to_hash_set()
+    }
 }
 
 #[cfg(feature = "NSEnumerator")]
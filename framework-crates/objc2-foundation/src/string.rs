@@ -3,6 +3,8 @@ use core::cmp;
 use core::ffi::c_void;
 use core::fmt;
 use core::ops::AddAssign;
+#[cfg(feature = "NSRange")]
+use core::ops::Range;
 use core::panic::RefUnwindSafe;
 use core::panic::UnwindSafe;
 use core::str;
@@ -13,7 +15,9 @@ use objc2::runtime::__nsstring::{nsstring_len, nsstring_to_str, UTF8_ENCODING};
 use objc2::{AnyThread, Message};
 
 use crate::util;
-use crate::{NSMutableString, NSString};
+#[cfg(feature = "NSRange")]
+use crate::NSRange;
+use crate::{NSMutableString, NSString, NSStringCompareOptions};
 
 // Even if an exception occurs inside a string method, the state of the string
 // (should) still be perfectly safe to access.
@@ -61,6 +65,13 @@ impl NSString {
     /// string is immutable, and that is why the lifetime of the returned
     /// string slice is also bound to the string itself.
     ///
+    /// The internal reference is returned (and no allocation happens) when
+    /// the string is immutable and already stored as UTF-8, which is common
+    /// for ASCII strings. Otherwise, the string is converted into a new,
+    /// autoreleased buffer. There is no way to only borrow from the string
+    /// without a pool, since a `&NSString` may point to a `NSMutableString`
+    /// whose storage could be reallocated while the slice is in use.
+    ///
     /// You should prefer the [`to_string`] method or the
     /// [`Display` implementation][display-impl] over this method when
     /// possible.
@@ -115,6 +126,533 @@ impl NSString {
     }
 
     // TODO: `initWithBytesNoCopy:length:encoding:` from `&'static str`.
+
+    /// Truncate the string to at most `max_graphemes` user-perceived
+    /// characters, appending `ellipsis` if anything was cut off.
+    ///
+    /// The string is split on composed character sequences (see
+    /// `rangeOfComposedCharacterSequenceAtIndex:`), so a character that is
+    /// made up of several UTF-16 code units (such as an emoji or a letter
+    /// followed by combining marks) is never split in half.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSString};
+    ///
+    /// let s = ns_string!("Hello, world!");
+    /// assert_eq!(s.truncated_to(5, ns_string!("…")).to_string(), "Hello…");
+    /// assert_eq!(s.truncated_to(20, ns_string!("…")).to_string(), "Hello, world!");
+    /// ```
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "rangeOfComposedCharacterSequenceAtIndex:")]
+    pub fn truncated_to(&self, max_graphemes: usize, ellipsis: &NSString) -> Retained<NSString> {
+        let len = self.length();
+        let mut index = 0;
+        let mut graphemes = 0;
+        while index < len {
+            if graphemes == max_graphemes {
+                let head = self.substringToIndex(index);
+                return head.stringByAppendingString(ellipsis);
+            }
+            index = self.rangeOfComposedCharacterSequenceAtIndex(index).end();
+            graphemes += 1;
+        }
+        NSString::stringWithString(self)
+    }
+
+    /// Pad the string on the left with `fill` until it is `width`
+    /// user-perceived characters long, i.e. align it to the right.
+    ///
+    /// Like [`truncated_to`][Self::truncated_to], this counts composed
+    /// character sequences, so characters made up of several UTF-16 code
+    /// units count as one. If the string is already at least `width`
+    /// characters long, an unchanged copy is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// assert_eq!(ns_string!("42").pad_left(5, '0').to_string(), "00042");
+    /// assert_eq!(ns_string!("abc").pad_right(5, '.').to_string(), "abc..");
+    /// assert_eq!(ns_string!("abc").center(7, '*').to_string(), "**abc**");
+    /// ```
+    #[cfg(feature = "NSRange")]
+    pub fn pad_left(&self, width: usize, fill: char) -> Retained<NSString> {
+        let missing = width.saturating_sub(self.grapheme_count());
+        self.padded(missing, 0, fill)
+    }
+
+    /// Pad the string on the right with `fill` until it is `width`
+    /// user-perceived characters long, i.e. align it to the left.
+    ///
+    /// See [`pad_left`][Self::pad_left] for details.
+    #[cfg(feature = "NSRange")]
+    pub fn pad_right(&self, width: usize, fill: char) -> Retained<NSString> {
+        let missing = width.saturating_sub(self.grapheme_count());
+        self.padded(0, missing, fill)
+    }
+
+    /// Pad the string on both sides with `fill` until it is `width`
+    /// user-perceived characters long, i.e. center it.
+    ///
+    /// If the padding cannot be split evenly, the extra character is put on
+    /// the right. See [`pad_left`][Self::pad_left] for details.
+    #[cfg(feature = "NSRange")]
+    pub fn center(&self, width: usize, fill: char) -> Retained<NSString> {
+        let missing = width.saturating_sub(self.grapheme_count());
+        self.padded(missing / 2, missing - missing / 2, fill)
+    }
+
+    /// The number of composed character sequences in the string.
+    #[cfg(feature = "NSRange")]
+    fn grapheme_count(&self) -> usize {
+        let len = self.length();
+        let mut index = 0;
+        let mut graphemes = 0;
+        while index < len {
+            index = self.rangeOfComposedCharacterSequenceAtIndex(index).end();
+            graphemes += 1;
+        }
+        graphemes
+    }
+
+    #[cfg(feature = "NSRange")]
+    fn padded(&self, left: usize, right: usize, fill: char) -> Retained<NSString> {
+        use alloc::string::String;
+
+        let repeat = |n| NSString::from_str(&core::iter::repeat(fill).take(n).collect::<String>());
+        repeat(left)
+            .stringByAppendingString(self)
+            .stringByAppendingString(&repeat(right))
+    }
+
+    /// An approximation of the number of columns that the string occupies
+    /// when displayed in a monospace font, such as in a terminal.
+    ///
+    /// Each user-perceived character (composed character sequence) counts
+    /// as one column, except for East Asian wide and fullwidth characters
+    /// and emoji, which count as two, and control characters, which count
+    /// as zero.
+    ///
+    /// This uses a simplified table of wide characters, and does not take
+    /// e.g. ambiguous-width characters into account, so the result may not
+    /// match what a given terminal renders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// assert_eq!(ns_string!("abc").display_width(), 3);
+    /// assert_eq!(ns_string!("日本").display_width(), 4);
+    /// ```
+    #[cfg(feature = "NSRange")]
+    pub fn display_width(&self) -> usize {
+        let len = self.length();
+        let mut index = 0;
+        let mut width = 0;
+        while index < len {
+            let range = self.rangeOfComposedCharacterSequenceAtIndex(index);
+            let units = (range.location..range.end()).map(|i| self.characterAtIndex(i));
+            width += cluster_width(units);
+            index = range.end();
+        }
+        width
+    }
+
+    /// The 1-based line and column of the given UTF-16 offset.
+    ///
+    /// Lines are split the same way as in `lineRangeForRange:`, that is, on
+    /// `\n`, `\r`, `\r\n`, U+0085, U+2028 and U+2029. The column is counted
+    /// in UTF-16 code units from the start of the line.
+    ///
+    /// The offset may be equal to the length of the string, in which case
+    /// the position is at the end of the last line, or at the start of a
+    /// new line if the string ends with a line terminator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `utf16_offset` is larger than the string's UTF-16 length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// let s = ns_string!("foo\r\nbar");
+    /// assert_eq!(s.line_and_column(0), (1, 1));
+    /// assert_eq!(s.line_and_column(6), (2, 2));
+    /// // The end of the string is on the last line.
+    /// assert_eq!(s.line_and_column(8), (2, 4));
+    /// ```
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "lineRangeForRange:")]
+    pub fn line_and_column(&self, utf16_offset: usize) -> (usize, usize) {
+        let len = self.length();
+        assert!(
+            utf16_offset <= len,
+            "offset {utf16_offset} out of bounds of string with length {len}"
+        );
+        // The end of the string only starts a new line if the string ends
+        // with a line terminator.
+        let ends_with_newline = len > 0
+            && matches!(
+                self.characterAtIndex(len - 1),
+                0x0A | 0x0D | 0x85 | 0x2028 | 0x2029
+            );
+        let mut line = 1;
+        let mut start = 0;
+        while start < len {
+            let end = self.lineRangeForRange(NSRange::new(start, 0)).end();
+            if utf16_offset < end || (end == len && !ends_with_newline) {
+                break;
+            }
+            line += 1;
+            start = end;
+        }
+        (line, utf16_offset - start + 1)
+    }
+
+    /// Create a new string with the UTF-16 code units in `range` replaced by
+    /// `replacement`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the string, or if the start
+    /// of the range is greater than the end. See
+    /// [`try_replacing_in_range`][Self::try_replacing_in_range] for a
+    /// non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// let s = ns_string!("Hello, world!");
+    /// assert_eq!(s.replacing_in_range(7..12, ns_string!("Rust")).to_string(), "Hello, Rust!");
+    /// ```
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "stringByReplacingCharactersInRange:withString:")]
+    pub fn replacing_in_range(
+        &self,
+        range: Range<usize>,
+        replacement: &NSString,
+    ) -> Retained<NSString> {
+        let len = self.length();
+        self.try_replacing_in_range(range.clone(), replacement)
+            .unwrap_or_else(|| panic!("range {range:?} out of bounds of string with length {len}"))
+    }
+
+    /// Create a new string with the UTF-16 code units in `range` replaced by
+    /// `replacement`, or `None` if the range is out of bounds.
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "stringByReplacingCharactersInRange:withString:")]
+    pub fn try_replacing_in_range(
+        &self,
+        range: Range<usize>,
+        replacement: &NSString,
+    ) -> Option<Retained<NSString>> {
+        if range.start > range.end || range.end > self.length() {
+            return None;
+        }
+        Some(self.stringByReplacingCharactersInRange_withString(range.into(), replacement))
+    }
+
+    /// Parse the string as an integer, the same way Foundation does.
+    ///
+    /// Unlike [`str::parse`], this is lenient: Leading whitespace and any
+    /// trailing characters that are not part of the number are ignored,
+    /// `0` is returned if the string does not start with a number, and the
+    /// result saturates at [`isize::MIN`] and [`isize::MAX`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// assert_eq!(ns_string!(" 42abc").to_integer(), 42);
+    /// assert_eq!(ns_string!("abc").to_integer(), 0);
+    /// ```
+    #[doc(alias = "integerValue")]
+    pub fn to_integer(&self) -> isize {
+        self.integerValue()
+    }
+
+    /// Parse the string as a floating point number, the same way Foundation
+    /// does.
+    ///
+    /// Like [`to_integer`][Self::to_integer], this ignores leading whitespace
+    /// and trailing characters, and returns `0.0` if the string does not
+    /// start with a number.
+    #[doc(alias = "doubleValue")]
+    pub fn to_double(&self) -> f64 {
+        self.doubleValue()
+    }
+
+    /// Parse the string as a boolean, the same way Foundation does.
+    ///
+    /// After skipping leading whitespace, and an optional sign and leading
+    /// zeros, this returns `true` if the string starts with one of `Y`, `y`,
+    /// `T`, `t` or a non-zero digit, and `false` otherwise. So e.g. `"yes"`,
+    /// `"True"` and `"  +01"` are all `true`.
+    #[doc(alias = "boolValue")]
+    pub fn to_bool(&self) -> bool {
+        self.boolValue()
+    }
+
+    /// The longest prefix that this string shares with `other`.
+    ///
+    /// The `options` control how characters are compared, e.g. pass
+    /// [`NSStringCompareOptions::CaseInsensitiveSearch`] to ignore case. The
+    /// returned prefix is taken from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSStringCompareOptions};
+    ///
+    /// let prefix = ns_string!("filename")
+    ///     .common_prefix(ns_string!("filepath"), NSStringCompareOptions::empty());
+    /// assert_eq!(prefix.to_string(), "file");
+    /// ```
+    #[doc(alias = "commonPrefixWithString:options:")]
+    pub fn common_prefix(
+        &self,
+        other: &NSString,
+        options: NSStringCompareOptions,
+    ) -> Retained<NSString> {
+        self.commonPrefixWithString_options(other, options)
+    }
+
+    /// Compare the string with `other` like the Finder does when sorting
+    /// file names.
+    ///
+    /// This is locale-aware, ignores case, and compares runs of digits by
+    /// their numeric value. Use [`Ord`] (or `compare` and `compare_options`,
+    /// whose result can be converted with [`Into`]) for a plain,
+    /// non-localized comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// let mut names = [ns_string!("File10"), ns_string!("file2"), ns_string!("File1")];
+    /// names.sort_by(|a, b| a.localized_standard_compare(b));
+    /// assert_eq!(names, [ns_string!("File1"), ns_string!("file2"), ns_string!("File10")]);
+    /// ```
+    #[doc(alias = "localizedStandardCompare:")]
+    #[cfg(feature = "NSObjCRuntime")]
+    pub fn localized_standard_compare(&self, other: &NSString) -> cmp::Ordering {
+        self.localizedStandardCompare(other).into()
+    }
+
+    /// An iterator over the parts of the string separated by `separator`.
+    ///
+    /// Like [`str::split`], empty parts are preserved, both between
+    /// consecutive separators and at the start and end of the string.
+    ///
+    /// The parts are collected up front with `componentsSeparatedByString:`,
+    /// use that directly if you need an [`NSArray`][crate::NSArray].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::ns_string;
+    ///
+    /// let parts: Vec<_> = ns_string!("a,,b")
+    ///     .split(ns_string!(","))
+    ///     .map(|part| part.to_string())
+    ///     .collect();
+    /// assert_eq!(parts, ["a", "", "b"]);
+    /// ```
+    #[cfg(all(feature = "NSArray", feature = "NSEnumerator"))]
+    #[doc(alias = "componentsSeparatedByString:")]
+    pub fn split(&self, separator: &NSString) -> crate::array::IntoIter<NSString> {
+        self.componentsSeparatedByString(separator).into_iter()
+    }
+
+    /// Create a new string with each key of `substitutions` replaced by its
+    /// value.
+    ///
+    /// The replacements are done in a single pass from the start of the
+    /// string, so the inserted values are never themselves replaced, and the
+    /// order of the dictionary does not matter. If multiple keys match at
+    /// the same position, the longest one is used. Empty keys are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSDictionary};
+    ///
+    /// let substitutions = NSDictionary::from_slices(
+    ///     &[ns_string!("{name}"), ns_string!("{place}")],
+    ///     &[ns_string!("Ferris"), ns_string!("the crate")],
+    /// );
+    /// let template = ns_string!("Hello {name}, welcome to {place}!");
+    /// assert_eq!(
+    ///     template.replacing_all(&substitutions).to_string(),
+    ///     "Hello Ferris, welcome to the crate!",
+    /// );
+    /// ```
+    #[cfg(feature = "NSDictionary")]
+    pub fn replacing_all(
+        &self,
+        substitutions: &crate::NSDictionary<NSString, NSString>,
+    ) -> Retained<NSString> {
+        use alloc::string::{String, ToString};
+        use alloc::vec::Vec;
+
+        let (keys, values) = substitutions.to_vecs();
+        let substitutions: Vec<(String, String)> = keys
+            .iter()
+            .zip(&values)
+            .filter(|(key, _)| !key.is_empty())
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        let string = self.to_string();
+        let mut result = String::with_capacity(string.len());
+        let mut rest = &*string;
+        while let Some(c) = rest.chars().next() {
+            let longest = substitutions
+                .iter()
+                .filter(|(key, _)| rest.starts_with(&**key))
+                .max_by_key(|(key, _)| key.len());
+            match longest {
+                Some((key, value)) => {
+                    result.push_str(value);
+                    rest = &rest[key.len()..];
+                }
+                None => {
+                    result.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        Self::from_str(&result)
+    }
+}
+
+/// The display width of a single composed character sequence.
+#[cfg(feature = "NSRange")]
+fn cluster_width(mut units: impl Iterator<Item = u16> + Clone) -> usize {
+    let c = match char::decode_utf16(units.clone()).next() {
+        Some(Ok(c)) => c,
+        // Unpaired surrogate
+        _ => return 1,
+    };
+    if c.is_control() {
+        return 0;
+    }
+    // A variation selector requesting emoji presentation.
+    if units.any(|unit| unit == 0xFE0F) {
+        return 2;
+    }
+    let is_wide = matches!(
+        c as u32,
+        0x1100..=0x115F // Hangul Jamo
+            | 0x2E80..=0x303E // CJK Radicals .. CJK Symbols and Punctuation
+            | 0x3041..=0x33FF // Hiragana .. CJK Compatibility
+            | 0x3400..=0x4DBF // CJK Unified Ideographs Extension A
+            | 0x4E00..=0x9FFF // CJK Unified Ideographs
+            | 0xA000..=0xA4CF // Yi
+            | 0xAC00..=0xD7A3 // Hangul Syllables
+            | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+            | 0xFE30..=0xFE4F // CJK Compatibility Forms
+            | 0xFF00..=0xFF60 // Fullwidth Forms
+            | 0xFFE0..=0xFFE6 // Fullwidth Signs
+            | 0x1F1E6..=0x1F1FF // Regional Indicators (flags)
+            | 0x1F300..=0x1F64F // Miscellaneous Symbols and Pictographs, Emoticons
+            | 0x1F680..=0x1F6FF // Transport and Map Symbols
+            | 0x1F900..=0x1F9FF // Supplemental Symbols and Pictographs
+            | 0x1FA70..=0x1FAFF // Symbols and Pictographs Extended-A
+            | 0x20000..=0x2FFFD // CJK Unified Ideographs Extension B ..
+            | 0x30000..=0x3FFFD // CJK Unified Ideographs Extension G ..
+    );
+    if is_wide {
+        2
+    } else {
+        1
+    }
+}
+
+/// [`Path`] conversion.
+///
+/// Note that `From<&Path>` cannot be implemented for `Retained<NSString>`,
+/// since neither the trait nor the type is defined in this crate. Use
+/// [`NSString::from_path`] instead.
+///
+/// [`Path`]: std::path::Path
+#[cfg(feature = "std")]
+#[cfg(unix)] // TODO: Use as_encoded_bytes/from_encoded_bytes_unchecked once in MSRV.
+#[cfg(all(feature = "NSFileManager", feature = "NSPathUtilities"))]
+impl NSString {
+    /// Create a string from the file system representation of the given
+    /// path.
+    ///
+    /// This properly handles paths that are not valid UTF-8 (such as those
+    /// that may exist on HFS+), though these may be lossily converted.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use objc2_foundation::NSString;
+    ///
+    /// let path = Path::new("/tmp/file.txt");
+    /// let string = NSString::from_path(path);
+    /// assert_eq!(string.to_string(), "/tmp/file.txt");
+    /// assert_eq!(string.to_path_buf(), path);
+    /// ```
+    #[doc(alias = "stringWithFileSystemRepresentation:length:")]
+    pub fn from_path(path: &std::path::Path) -> Retained<Self> {
+        use std::os::unix::ffi::OsStrExt;
+
+        let bytes = path.as_os_str().as_bytes();
+        let ptr = core::ptr::NonNull::new(bytes.as_ptr() as *mut u8)
+            .unwrap()
+            .cast();
+        let manager = crate::NSFileManager::defaultManager();
+        // SAFETY: The pointer and length are valid for the duration of the
+        // call (the method does not require the string to be NUL-terminated).
+        unsafe { manager.stringWithFileSystemRepresentation_length(ptr, bytes.len()) }
+    }
+
+    /// Convert the string to a path using its file system representation.
+    ///
+    /// On Apple platforms, this converts the string to the decomposed form
+    /// that the file system expects.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics (by raising an exception) if the string could not be converted
+    /// to the file system representation.
+    #[doc(alias = "fileSystemRepresentation")]
+    pub fn to_path_buf(&self) -> std::path::PathBuf {
+        use std::ffi::{CStr, OsStr};
+        use std::os::unix::ffi::OsStrExt;
+
+        if self.length() == 0 {
+            // `fileSystemRepresentation` throws an exception on empty
+            // strings on some platforms.
+            return std::path::PathBuf::new();
+        }
+
+        objc2::rc::autoreleasepool(|_| {
+            // Returns a pointer to a C string (or throws an exception).
+            let ptr = self.fileSystemRepresentation();
+            // SAFETY: The returned C string is autoreleased, and is valid
+            // until the end of the current autorelease pool, which is where
+            // we copy it into a `PathBuf`.
+            let cstr = unsafe { CStr::from_ptr(ptr.as_ptr()) };
+            std::path::PathBuf::from(OsStr::from_bytes(cstr.to_bytes()))
+        })
+    }
 }
 
 impl NSMutableString {
@@ -124,6 +662,75 @@ impl NSMutableString {
     pub fn from_str(string: &str) -> Retained<Self> {
         unsafe { init_with_str(Self::alloc(), string) }
     }
+
+    /// Append the given string slice to the end of this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSMutableString;
+    ///
+    /// let s = NSMutableString::from_str("Hello");
+    /// s.push_str(", world!");
+    /// assert_eq!(s.to_string(), "Hello, world!");
+    /// ```
+    #[doc(alias = "appendString:")]
+    pub fn push_str(&self, string: &str) {
+        self.appendString(&NSString::from_str(string));
+    }
+
+    /// Insert the given string slice at the given UTF-16 code unit offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the [`length`][NSString::length] of
+    /// the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSMutableString;
+    ///
+    /// let s = NSMutableString::from_str("Hello!");
+    /// s.insert_str(5, ", world");
+    /// assert_eq!(s.to_string(), "Hello, world!");
+    /// ```
+    #[doc(alias = "insertString:atIndex:")]
+    pub fn insert_str(&self, index: usize, string: &str) {
+        let len = self.length();
+        assert!(
+            index <= len,
+            "index {index} out of bounds of string with length {len}",
+        );
+        self.insertString_atIndex(&NSString::from_str(string), index);
+    }
+
+    /// Replace the UTF-16 code units in `range` with the given string slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the string, or if the start
+    /// of the range is greater than the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSMutableString;
+    ///
+    /// let s = NSMutableString::from_str("Hello, world!");
+    /// s.replace_range(7..12, "Rust");
+    /// assert_eq!(s.to_string(), "Hello, Rust!");
+    /// ```
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "replaceCharactersInRange:withString:")]
+    pub fn replace_range(&self, range: Range<usize>, string: &str) {
+        let len = self.length();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range {range:?} out of bounds of string with length {len}",
+        );
+        self.replaceCharactersInRange_withString(range.into(), &NSString::from_str(string));
+    }
 }
 
 unsafe fn init_with_str<T: Message>(obj: Allocated<T>, string: &str) -> Retained<T> {
@@ -109,6 +109,125 @@ fn test_iter() {
     assert_eq!(iterations, 4 * 4);
 }
 
+#[test]
+fn test_into_iter_owned() {
+    let array = sample_array(4);
+    let expected: Vec<*const NSObject> = (0..4)
+        .map(|i| Retained::as_ptr(&array.objectAtIndex(i)))
+        .collect();
+
+    let vec: Vec<Retained<NSObject>> = array.into_iter().collect();
+    assert_eq!(vec.len(), 4);
+    // The array is gone, but the items are still alive.
+    for (obj, expected) in vec.iter().zip(expected) {
+        assert!(ptr::eq(Retained::as_ptr(obj), expected));
+    }
+}
+
+#[test]
+fn test_collect() {
+    use crate::NSMutableArray;
+
+    let array: Retained<NSArray<NSObject>> = (0..4).map(|_| NSObject::new()).collect();
+    assert_eq!(array.len(), 4);
+
+    let mutable: Retained<NSMutableArray<NSObject>> = array.iter().collect();
+    assert_eq!(mutable.len(), 4);
+    mutable.addObject(&NSObject::new());
+    assert_eq!(mutable.len(), 5);
+    for i in 0..4 {
+        assert_eq!(mutable.objectAtIndex(i), array.objectAtIndex(i));
+    }
+
+    let empty: Retained<NSArray<NSObject>> = core::iter::empty::<Retained<NSObject>>().collect();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_iter_sizes() {
+    for len in [0, 1, 2, 15, 16, 17, 1000] {
+        let array: Retained<NSArray<NSNumber>> = (0..len).map(NSNumber::new_u32).collect();
+
+        let values: Vec<u32> = array.iter().map(|n| n.as_u32()).collect();
+        assert_eq!(values, (0..len).collect::<Vec<_>>());
+
+        let mut count = 0;
+        for (i, obj) in (&*array).into_iter().enumerate() {
+            assert_eq!(obj.as_u32(), i as u32);
+            count += 1;
+        }
+        assert_eq!(count, len);
+
+        let (lower, upper) = array.iter().size_hint();
+        assert!(lower <= len as usize);
+        assert_eq!(upper, Some(len as usize));
+    }
+}
+
+#[test]
+#[cfg(feature = "block2")]
+fn test_for_each_indexed() {
+    let array = sample_number_array(5);
+
+    let mut pairs = Vec::new();
+    array.for_each_indexed(|i, obj| pairs.push((i, obj.as_u8())));
+
+    let expected: Vec<_> = (0..array.count())
+        .map(|i| (i, array.objectAtIndex(i).as_u8()))
+        .collect();
+    assert_eq!(pairs, expected);
+
+    let mut called = false;
+    NSArray::<NSNumber>::new().for_each_indexed(|_, _| called = true);
+    assert!(!called);
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "std")]
+fn test_for_each_indexed_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let array = sample_number_array(5);
+
+    let mut visited = Vec::new();
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        array.for_each_indexed(|i, _| {
+            visited.push(i);
+            if i == 2 {
+                panic!("enumeration failed");
+            }
+        });
+    }));
+    let payload = res.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"enumeration failed"));
+
+    // The enumeration was stopped after the panic.
+    assert_eq!(visited, [0, 1, 2]);
+}
+
+#[test]
+fn test_reverse() {
+    let array = sample_number_array(5);
+
+    let rev: Vec<_> = array.rev_iter().map(|n| n.as_u8()).collect();
+    let expected: Vec<_> = (0..array.len())
+        .rev()
+        .map(|i| array.objectAtIndex(i).as_u8())
+        .collect();
+    assert_eq!(rev, expected);
+    assert_eq!(rev, [4, 3, 2, 1, 0]);
+
+    let reversed = array.reversed();
+    assert_eq!(reversed.to_vec(), array.rev_iter().collect::<Vec<_>>());
+    // The original is unchanged.
+    assert_eq!(array.objectAtIndex(0).as_u8(), 0);
+
+    let empty = NSArray::<NSNumber>::new();
+    assert_eq!(empty.rev_iter().count(), 0);
+    assert!(empty.reversed().is_empty());
+}
+
 #[test]
 fn test_iter_fused() {
     // Not actually documented, nor is FusedIterator implemented for the
@@ -138,6 +257,28 @@ fn test_two_iters() {
     for (_, _) in iter1.zip(iter2) {}
 }
 
+#[test]
+#[cfg(feature = "NSString")]
+fn test_collect_refs() {
+    use crate::{ns_string, NSString};
+
+    let slice: &[&NSString] = &[ns_string!("a"), ns_string!("b"), ns_string!("c")];
+    let array: Retained<NSArray<NSString>> = slice.iter().copied().collect();
+    assert_eq!(array.count(), 3);
+    for (i, s) in slice.iter().enumerate() {
+        assert!(ptr::eq(&*array.objectAtIndex(i), *s));
+    }
+
+    // Also works with borrows of retained objects.
+    let owned = [NSString::from_str("x"), NSString::from_str("y")];
+    let array: Retained<NSArray<NSString>> = owned.iter().map(|s| &**s).collect();
+    assert_eq!(array.to_vec(), owned);
+
+    let none: [&NSString; 0] = [];
+    let empty: Retained<NSArray<NSString>> = none.iter().copied().collect();
+    assert!(empty.is_empty());
+}
+
 #[test]
 fn test_objects_in_range() {
     let array = sample_array(4);
@@ -198,6 +339,105 @@ fn test_cast() {
     assert_eq!(unsafe { value.get::<i64>() }, 42);
 }
 
+#[test]
+fn test_dedup_consecutive() {
+    let array = NSArray::from_retained_slice(&[1, 1, 2, 3, 3, 3, 4, 5, 5].map(NSNumber::new_i32));
+    let deduped = array.dedup_consecutive(|a, b| a.as_i32().cmp(&b.as_i32()));
+    let values: Vec<_> = deduped.to_vec().iter().map(|n| n.as_i32()).collect();
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+    // The original is unchanged.
+    assert_eq!(array.len(), 9);
+
+    // Only consecutive elements are removed.
+    let array = NSArray::from_retained_slice(&[1, 2, 1].map(NSNumber::new_i32));
+    let deduped = array.dedup_consecutive(|a, b| a.as_i32().cmp(&b.as_i32()));
+    assert_eq!(deduped.len(), 3);
+
+    let empty = NSArray::<NSNumber>::new();
+    assert!(empty.dedup_consecutive(|_, _| unreachable!()).is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "NSObject", feature = "NSString"))]
+fn test_try_downcast_elements() {
+    use crate::{NSMutableArray, NSString};
+
+    let strings: Retained<NSArray<NSObject>> = NSArray::from_retained_slice(&[
+        NSString::from_str("a").into_super(),
+        NSString::from_str("b").into_super(),
+    ]);
+    let downcast = strings.try_downcast_elements::<NSString>().unwrap();
+    assert_eq!(downcast.len(), 2);
+    assert_eq!(downcast.objectAtIndex(1).to_string(), "b");
+
+    let mixed: Retained<NSArray<NSObject>> = NSArray::from_retained_slice(&[
+        NSString::from_str("a").into_super(),
+        NSNumber::new_i32(1).into_super().into_super(),
+    ]);
+    assert!(mixed.try_downcast_elements::<NSString>().is_none());
+    assert!(mixed.try_downcast_elements::<NSObject>().is_some());
+
+    let empty = NSArray::<NSObject>::new().try_downcast_elements::<NSString>();
+    assert_eq!(empty.map(|array| array.len()), Some(0));
+
+    // Mutating the original array afterwards does not affect the result.
+    let mutable = NSMutableArray::from_retained_slice(&[NSString::from_str("a").into_super()]);
+    let downcast = mutable.try_downcast_elements::<NSString>().unwrap();
+    mutable.addObject(&NSObject::new());
+    assert_eq!(downcast.len(), 1);
+}
+
+#[test]
+#[cfg(all(feature = "NSDictionary", feature = "NSString"))]
+fn test_zip_into_dictionary() {
+    use alloc::string::ToString;
+
+    use crate::{ns_string, NSMutableString};
+
+    let keys = NSArray::from_slice(&[ns_string!("a"), ns_string!("b"), ns_string!("c")]);
+    let values = sample_number_array(3);
+    let dict = keys.zip_into_dictionary(&values);
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.objectForKey(ns_string!("a")).unwrap().as_i32(), 0);
+    assert_eq!(dict.objectForKey(ns_string!("b")).unwrap().as_i32(), 1);
+    assert_eq!(dict.objectForKey(ns_string!("c")).unwrap().as_i32(), 2);
+
+    // Truncated to the shorter array.
+    let dict = keys.zip_into_dictionary(&sample_number_array(2));
+    assert_eq!(dict.len(), 2);
+    assert!(dict.objectForKey(ns_string!("c")).is_none());
+    let dict = NSArray::from_slice(&[ns_string!("a")]).zip_into_dictionary(&values);
+    assert_eq!(dict.len(), 1);
+
+    // Mutable keys are copied.
+    let key = NSMutableString::from_str("a");
+    let keys = NSArray::from_slice(&[&*key]);
+    let dict = keys.zip_into_dictionary(&values);
+    key.appendString(ns_string!("b"));
+    assert_eq!(dict.allKeys().objectAtIndex(0).to_string(), "a");
+}
+
+#[test]
+#[cfg(all(feature = "NSDictionary", feature = "NSString"))]
+fn test_group_by() {
+    use crate::{ns_string, NSString};
+
+    let numbers = sample_number_array(7);
+    let groups =
+        numbers.group_by(|n| NSString::from_str(if n.as_u8() % 2 == 0 { "even" } else { "odd" }));
+    assert_eq!(groups.len(), 2);
+
+    let values = |key| -> Vec<u8> {
+        let group = groups.objectForKey(key).unwrap();
+        group.to_vec().iter().map(|n| n.as_u8()).collect()
+    };
+    assert_eq!(values(ns_string!("even")), [0, 2, 4, 6]);
+    assert_eq!(values(ns_string!("odd")), [1, 3, 5]);
+
+    let groups = NSArray::<NSNumber>::new().group_by(|_| NSString::new());
+    assert!(groups.is_empty());
+}
+
 #[test]
 #[cfg(feature = "objc2-core-foundation")]
 #[cfg(not(feature = "gnustep-1-7"))]
@@ -74,6 +74,22 @@ fn test_debug() {
     assert_eq!(format!("{s:?}"), expected);
 }
 
+#[test]
+#[cfg(all(feature = "NSDictionary", feature = "NSRange"))]
+fn test_attribute_at_index() {
+    let color = ns_string!("red");
+    let s = NSMutableAttributedString::from_nsstring(ns_string!("Hello world!"));
+    unsafe { s.addAttribute_value_range(ns_string!("color"), color, crate::NSRange::from(6..11)) };
+
+    assert!(s.attribute(ns_string!("color"), 0).is_none());
+    assert!(s.attribute(ns_string!("color"), 11).is_none());
+    assert!(s.attribute(ns_string!("other"), 6).is_none());
+
+    let (value, range) = s.attribute(ns_string!("color"), 8).unwrap();
+    assert_eq!(value.downcast::<NSString>().unwrap().to_string(), "red");
+    assert_eq!(range, 6..11);
+}
+
 #[test]
 fn test_new_mutable() {
     let s = NSMutableAttributedString::new();
@@ -97,3 +113,40 @@ fn test_copy_mutable() {
     assert_ne!(Retained::as_ptr(&s1), Retained::as_ptr(&s3));
     assert!(s3.isKindOfClass(NSMutableAttributedString::class()));
 }
+
+#[test]
+#[cfg(all(feature = "NSDictionary", feature = "NSObject", feature = "NSRange"))]
+fn test_builder() {
+    use crate::{AttributedStringBuilder, NSDictionary};
+
+    let key = ns_string!("color");
+    let value = |s: &'static NSString| -> &'static AnyObject { s };
+    let red = NSDictionary::from_slices(&[key], &[value(ns_string!("red"))]);
+    // Equal, but not the same dictionary.
+    let red_copy = NSDictionary::from_slices(&[key], &[value(ns_string!("red"))]);
+    let blue = NSDictionary::from_slices(&[key], &[value(ns_string!("blue"))]);
+
+    let mut builder = AttributedStringBuilder::new();
+    unsafe {
+        builder
+            .push_str_with_attributes("Hello ", &red)
+            // Merged with the previous run.
+            .push_str_with_attributes("🌍", &red_copy)
+            .push_str(" and ")
+            .push_str_with_attributes("you", &blue)
+            .push_str_with_attributes("", &red);
+    }
+    let s = builder.finish();
+    assert_eq!(s.string().to_string(), "Hello 🌍 and you");
+
+    let color = |index| {
+        let (value, range) = s.attribute(key, index)?;
+        Some((value.downcast::<NSString>().unwrap().to_string(), range))
+    };
+    // Ranges are in UTF-16 code units, and the emoji takes up two.
+    assert_eq!(color(0), Some(("red".to_string(), 0..8)));
+    assert_eq!(color(7), Some(("red".to_string(), 0..8)));
+    assert_eq!(color(8), None);
+    assert_eq!(color(13), Some(("blue".to_string(), 13..16)));
+    assert_eq!(color(15), Some(("blue".to_string(), 13..16)));
+}
@@ -17,3 +17,37 @@ fn try_running_functions() {
     assert_eq!(format!("{:?}", bundle.infoDictionary().unwrap()), "{}");
     assert_eq!(bundle.name(), None);
 }
+
+#[test]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "std")]
+#[cfg(target_vendor = "apple")]
+fn localized_string() {
+    use crate::NSString;
+    use std::fs;
+
+    let dir = std::env::temp_dir().join(format!(
+        "objc2-foundation-localized-string-{}.bundle",
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("Localizable.strings"),
+        "\"greeting\" = \"Hello, world!\";\n",
+    )
+    .unwrap();
+    fs::write(dir.join("Other.strings"), "\"greeting\" = \"Hi!\";\n").unwrap();
+
+    let path = NSString::from_str(dir.to_str().unwrap());
+    let bundle = NSBundle::bundleWithPath(&path).unwrap();
+
+    let lookup = |key: &str, table: Option<&str>| bundle.localized_string(key, table).to_string();
+    assert_eq!(lookup("greeting", None), "Hello, world!");
+    assert_eq!(lookup("greeting", Some("Other")), "Hi!");
+
+    // Missing keys and tables fall back to the key.
+    assert_eq!(lookup("missing", None), "missing");
+    assert_eq!(lookup("greeting", Some("Missing")), "greeting");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
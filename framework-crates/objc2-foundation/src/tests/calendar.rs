@@ -0,0 +1,51 @@
+#![cfg(feature = "NSCalendar")]
+#![cfg(feature = "NSDate")]
+#![cfg(feature = "NSTimeZone")]
+#![cfg(feature = "NSString")]
+use objc2::rc::Retained;
+
+use crate::{NSCalendar, NSCalendarUnit, NSDate, NSTimeZone};
+
+fn gregorian_utc() -> Retained<NSCalendar> {
+    let calendar =
+        NSCalendar::calendarWithIdentifier(unsafe { crate::NSCalendarIdentifierGregorian })
+            .unwrap();
+    calendar.setTimeZone(&NSTimeZone::timeZoneForSecondsFromGMT(0));
+    calendar
+}
+
+#[test]
+fn components() {
+    let calendar = gregorian_utc();
+
+    // Saturday, 2024-06-15 12:00:00 UTC
+    let date = NSDate::dateWithTimeIntervalSince1970(1718452800.0);
+    assert_eq!(
+        calendar.component_fromDate(NSCalendarUnit::Year, &date),
+        2024
+    );
+    assert_eq!(calendar.component_fromDate(NSCalendarUnit::Month, &date), 6);
+    assert_eq!(calendar.component_fromDate(NSCalendarUnit::Day, &date), 15);
+    // Weekdays are 1-based, starting on Sunday.
+    assert_eq!(
+        calendar.component_fromDate(NSCalendarUnit::Weekday, &date),
+        7
+    );
+    assert_eq!(calendar.component_fromDate(NSCalendarUnit::Hour, &date), 12);
+}
+
+#[test]
+#[cfg_attr(feature = "gnustep-1-7", ignore = "not implemented on GNUStep")]
+fn weekend_and_today() {
+    let calendar = gregorian_utc();
+
+    // Saturday, 2024-06-15 12:00:00 UTC
+    let saturday = NSDate::dateWithTimeIntervalSince1970(1718452800.0);
+    assert!(calendar.isDateInWeekend(&saturday));
+    // Monday, 2024-06-17 12:00:00 UTC
+    let monday = NSDate::dateWithTimeIntervalSince1970(1718625600.0);
+    assert!(!calendar.isDateInWeekend(&monday));
+
+    assert!(!calendar.isDateInToday(&saturday));
+    assert!(calendar.isDateInToday(&NSDate::new()));
+}
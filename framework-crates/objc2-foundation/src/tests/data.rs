@@ -11,6 +11,19 @@ fn test_bytes() {
     assert_eq!(data.to_vec(), bytes);
 }
 
+#[test]
+fn test_bytes_round_trip() {
+    fn takes_slice(bytes: &[u8]) -> usize {
+        bytes.len()
+    }
+
+    let data = NSData::with_bytes(b"hello");
+    assert_eq!(data.to_vec(), b"hello");
+    let bytes = unsafe { data.as_bytes_unchecked() };
+    assert_eq!(bytes, b"hello");
+    assert_eq!(takes_slice(bytes), 5);
+}
+
 #[test]
 fn test_no_bytes() {
     let data = NSData::new();
@@ -27,6 +40,41 @@ fn test_from_vec() {
     assert_eq!(unsafe { data.as_bytes_unchecked() }.as_ptr(), bytes_ptr);
 }
 
+#[test]
+fn test_starts_ends_with() {
+    let png = NSData::with_bytes(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR");
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert!(!png.starts_with(b"GIF89a"));
+    assert!(png.ends_with(b"IHDR"));
+    assert!(!png.ends_with(b"IEND"));
+
+    assert!(png.starts_with(&[]));
+    assert!(png.ends_with(&[]));
+
+    let empty = NSData::new();
+    assert!(empty.starts_with(&[]));
+    assert!(!empty.starts_with(&[0]));
+    assert!(!empty.ends_with(&[0]));
+}
+
+#[test]
+fn test_find() {
+    let data = NSData::with_bytes(b"header\x00\xffpayload\x00\xfftrailer");
+    assert_eq!(data.find(b"\x00\xff"), Some(6));
+    assert_eq!(data.find_range(b"\x00\xff"), Some(6..8));
+    assert_eq!(data.find(b"payload"), Some(8));
+    assert_eq!(data.find(b"header"), Some(0));
+    assert_eq!(data.find_range(b"trailer"), Some(17..24));
+
+    assert_eq!(data.find(b"missing"), None);
+    assert_eq!(data.find_range(b"trailer!"), None);
+    assert_eq!(data.find(&[]), Some(0));
+
+    let empty = NSData::new();
+    assert_eq!(empty.find(&[0]), None);
+    assert_eq!(empty.find_range(&[]), Some(0..0));
+}
+
 #[test]
 fn test_debug() {
     let bytes = [3, 7, 16, 52, 112, 19];
@@ -41,3 +89,228 @@ fn test_collect() {
     let data: objc2::rc::Retained<NSData> = bytes.into_iter().collect();
     assert_eq!(format!("{data:?}"), "[3, 7, 16, 52, 112, 19]");
 }
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(unix)]
+#[cfg(not(feature = "gnustep-1-7"))]
+#[cfg(all(feature = "NSURL", feature = "NSError", feature = "FoundationErrors"))]
+#[cfg(all(feature = "NSDictionary", feature = "NSString"))]
+fn test_write_to_file() {
+    use std::fs;
+    use std::path::Path;
+
+    let path = std::env::temp_dir().join(format!(
+        "objc2-foundation-write-to-file-{}",
+        std::process::id()
+    ));
+
+    let data = NSData::with_bytes(b"hello");
+    data.write_to_file(&path, true).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"hello");
+
+    let data = NSData::with_bytes(b"world!");
+    data.write_to_file(&path, false).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"world!");
+    fs::remove_file(&path).unwrap();
+
+    let path = Path::new("/objc2-foundation-does-not-exist/file");
+    let err = data.write_to_file(path, true).unwrap_err();
+    assert_eq!(&*err.domain(), unsafe { crate::NSCocoaErrorDomain });
+
+    assert!(data.write_to_file(Path::new(""), true).is_err());
+}
+
+#[test]
+#[cfg(feature = "NSString")]
+fn test_hex() {
+    use crate::{ns_string, NSString};
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let data = NSData::with_bytes(&bytes);
+    let hex = data.to_hex(false);
+    assert_eq!(hex.len(), 512);
+    assert!(hex.to_string().starts_with("000102030405060708090a0b"));
+    assert_eq!(NSData::from_hex(&hex).unwrap().to_vec(), bytes);
+    let hex = data.to_hex(true);
+    assert!(hex.to_string().ends_with("FCFDFEFF"));
+    assert_eq!(NSData::from_hex(&hex).unwrap().to_vec(), bytes);
+
+    assert_eq!(NSData::new().to_hex(false).to_string(), "");
+    assert_eq!(NSData::from_hex(&NSString::new()).unwrap().len(), 0);
+    assert_eq!(
+        NSData::from_hex(ns_string!("0aBc")).unwrap().to_vec(),
+        [0x0a, 0xbc]
+    );
+
+    assert!(NSData::from_hex(ns_string!("abc")).is_none());
+    assert!(NSData::from_hex(ns_string!("0g")).is_none());
+    assert!(NSData::from_hex(ns_string!("0x12")).is_none());
+    assert!(NSData::from_hex(ns_string!("12 34")).is_none());
+    assert!(NSData::from_hex(ns_string!("é1")).is_none());
+}
+
+#[test]
+#[cfg(feature = "NSString")]
+fn test_base64() {
+    use crate::{ns_string, NSDataBase64DecodingOptions, NSDataBase64EncodingOptions};
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    let encode = |bytes: &[u8]| {
+        NSData::with_bytes(bytes)
+            .to_base64(NSDataBase64EncodingOptions::empty())
+            .to_string()
+    };
+    // Test vectors from RFC 4648.
+    assert_eq!(encode(b""), "");
+    assert_eq!(encode(b"f"), "Zg==");
+    assert_eq!(encode(b"fo"), "Zm8=");
+    assert_eq!(encode(b"foo"), "Zm9v");
+    assert_eq!(encode(b"foob"), "Zm9vYg==");
+    assert_eq!(encode(b"fooba"), "Zm9vYmE=");
+    assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    assert_eq!(encode(&[0xfb, 0xff]), "+/8=");
+
+    let decode = |string| {
+        NSData::from_base64(string, NSDataBase64DecodingOptions::empty()).map(|data| data.to_vec())
+    };
+    assert_eq!(decode(ns_string!("")).unwrap(), b"");
+    assert_eq!(decode(ns_string!("Zm9vYmE=")).unwrap(), b"fooba");
+    assert_eq!(decode(ns_string!("+/8=")).unwrap(), [0xfb, 0xff]);
+    assert_eq!(decode(ns_string!("Zm9v!")), None);
+    assert_eq!(decode(ns_string!("Zm9\r\nv")), None);
+
+    // Line wrapping.
+    let bytes: Vec<u8> = (0..=255).collect();
+    let data = NSData::with_bytes(&bytes);
+    let wrapped = data.to_base64(NSDataBase64EncodingOptions::Encoding76CharacterLineLength);
+    let lines: Vec<_> = wrapped
+        .to_string()
+        .split("\r\n")
+        .map(|line| line.len())
+        .collect();
+    assert_eq!(lines, [76, 76, 76, 76, 40]);
+    let wrapped = data.to_base64(
+        NSDataBase64EncodingOptions::Encoding64CharacterLineLength
+            | NSDataBase64EncodingOptions::EncodingEndLineWithLineFeed,
+    );
+    assert!(!wrapped.to_string().contains('\r'));
+    assert_eq!(wrapped.to_string().lines().next().unwrap().len(), 64);
+
+    // Wrapped output can only be decoded when ignoring line endings.
+    assert!(NSData::from_base64(&wrapped, NSDataBase64DecodingOptions::empty()).is_none());
+    let decoded = NSData::from_base64(
+        &wrapped,
+        NSDataBase64DecodingOptions::IgnoreUnknownCharacters,
+    );
+    assert_eq!(decoded.unwrap(), data);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_reader() {
+    use alloc::vec::Vec;
+    use std::io::Read;
+
+    let bytes: Vec<u8> = (0..10).collect();
+    let data = NSData::with_bytes(&bytes);
+
+    // Read in chunks that don't evenly divide the length.
+    let mut reader = data.reader();
+    let mut buf = [0; 3];
+    let mut chunks = Vec::new();
+    loop {
+        let n = reader.read(&mut buf).unwrap();
+        if n == 0 {
+            break;
+        }
+        chunks.push(buf[..n].to_vec());
+    }
+    assert_eq!(chunks, [&[0, 1, 2][..], &[3, 4, 5], &[6, 7, 8], &[9]]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+    let mut reader = data.reader();
+    reader.read_exact(&mut buf).unwrap();
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, &bytes[3..]);
+
+    let mut empty = Vec::new();
+    NSData::new().reader().read_to_end(&mut empty).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "NSRange"))]
+fn test_reader_mutated() {
+    use std::io::Read;
+
+    use crate::NSMutableData;
+
+    let data = NSMutableData::with_bytes(&[1, 2, 3, 4]);
+    let mut reader = data.reader();
+    let mut buf = [0; 2];
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+
+    data.set_bytes(&[5]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+    data.set_bytes(&[5, 6, 7, 8, 9]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [7, 8]);
+}
+
+#[test]
+#[cfg(all(feature = "NSArray", feature = "NSEnumerator", feature = "NSValue"))]
+fn test_number_array() {
+    use crate::{NSArray, NSNumber};
+
+    let bytes = [0, 1, 127, 128, 255];
+    let data = NSData::with_bytes(&bytes);
+    let array = data.to_number_array();
+    let values: alloc::vec::Vec<_> = array.to_vec().iter().map(|n| n.as_u8()).collect();
+    assert_eq!(values, bytes);
+    assert_eq!(array.to_data_u8(), data);
+
+    let array = NSArray::from_retained_slice(&[7, 42].map(NSNumber::new_u8));
+    assert_eq!(array.to_data_u8().to_number_array(), array);
+
+    // Numbers that don't fit in a byte are truncated.
+    let array = NSArray::from_retained_slice(&[NSNumber::new_i32(256 + 3)]);
+    assert_eq!(array.to_data_u8().to_vec(), [3]);
+
+    assert!(NSData::new().to_number_array().is_empty());
+    assert!(NSArray::<NSNumber>::new().to_data_u8().is_empty());
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_subdata() {
+    let data = NSData::with_bytes(b"hello world");
+    let sub = data.subdata(2..7);
+    assert_eq!(*sub, b"llo w"[..]);
+    assert_eq!(sub.len(), 5);
+    assert_eq!(*data.subdata(0..data.len()), b"hello world"[..]);
+    assert!(data.subdata(11..11).is_empty());
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+#[should_panic = "range 8..12 out of bounds of data with length 11"]
+fn test_subdata_out_of_bounds() {
+    let data = NSData::with_bytes(b"hello world");
+    let _ = data.subdata(8..12);
+}
+
+#[test]
+fn test_eq_bytes() {
+    let data = NSData::with_bytes(&[1, 2, 3]);
+    assert_eq!(*data, b"\x01\x02\x03"[..]);
+    assert_ne!(*data, b"\x01\x02"[..]);
+    assert_ne!(*data, b"\x01\x02\x04"[..]);
+    assert_ne!(*data, b"\x01\x02\x03\x04"[..]);
+    assert_eq!(*NSData::new(), b""[..]);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "NSDate")]
+#![cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{NSDate, NSTimeIntervalSince1970};
+
+#[test]
+fn system_time_round_trip() {
+    let times = [
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::new(1_718_452_800, 123_456_000),
+        // Before the reference date.
+        UNIX_EPOCH + Duration::new(978_307_199, 999_000_000),
+        // Before the Unix epoch.
+        UNIX_EPOCH - Duration::new(86_400, 250_000_000),
+        SystemTime::now(),
+    ];
+    for time in times {
+        let date = NSDate::from_system_time(time);
+        let round_trip = SystemTime::from(&*date);
+        let diff = match round_trip.duration_since(time) {
+            Ok(diff) => diff,
+            Err(err) => err.duration(),
+        };
+        assert!(
+            diff < Duration::from_micros(1),
+            "{time:?} != {round_trip:?}"
+        );
+    }
+}
+
+#[test]
+fn reference_date() {
+    let date = NSDate::dateWithTimeIntervalSinceReferenceDate(0.0);
+    let expected = UNIX_EPOCH + Duration::from_secs(NSTimeIntervalSince1970 as u64);
+    assert_eq!(date.to_system_time(), Some(expected));
+
+    let date = NSDate::dateWithTimeIntervalSinceReferenceDate(-1.5);
+    assert_eq!(
+        date.to_system_time(),
+        Some(expected - Duration::from_millis(1500))
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let date = NSDate::dateWithTimeIntervalSince1970(f64::INFINITY);
+    assert_eq!(date.to_system_time(), None);
+}
@@ -21,3 +21,159 @@ fn test_decimal_encoding() {
     let obj = NSDecimalNumber::initWithDecimal(NSDecimalNumber::alloc(), decimal);
     assert_eq!(decimal, obj.decimalValue());
 }
+
+fn decimal(mantissa: u64, exponent: i16) -> objc2::rc::Retained<NSDecimalNumber> {
+    NSDecimalNumber::decimalNumberWithMantissa_exponent_isNegative(mantissa, exponent, false)
+}
+
+#[test]
+fn test_arithmetic() {
+    use alloc::string::ToString;
+
+    let a = decimal(15, -1);
+    let b = decimal(25, -2);
+    assert_eq!((&*a + &*b).to_string(), "1.75");
+    assert_eq!((&*a - &*b).to_string(), "1.25");
+    assert_eq!((&*a * &*b).to_string(), "0.375");
+    assert_eq!((&*a / &*b).to_string(), "6");
+
+    // Exact, unlike with floating point.
+    let sum = &*decimal(1, -1) + &*decimal(2, -1);
+    assert_eq!(sum, decimal(3, -1));
+    assert_eq!(sum.doubleValue(), 0.3);
+}
+
+#[test]
+fn test_errors_return_nan() {
+    use alloc::string::ToString;
+
+    let nan = NSDecimalNumber::notANumber();
+
+    let res = &*decimal(1, 0) / &*NSDecimalNumber::zero();
+    assert_eq!(res.to_string(), nan.to_string());
+
+    let max = NSDecimalNumber::maximumDecimalNumber();
+    let res = &*max * &*decimal(10, 0);
+    assert_eq!(res.to_string(), nan.to_string());
+}
+
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+fn test_ordering() {
+    let a = decimal(15, -1);
+    let b = decimal(25, -2);
+    assert!(a > b);
+    assert!(b < a);
+    assert_eq!(a.cmp(&decimal(150, -2)), core::cmp::Ordering::Equal);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "gnustep-1-7",
+    ignore = "has different encoding, yet unsupported"
+)]
+fn test_decimal_arithmetic() {
+    use alloc::string::ToString;
+
+    use crate::{NSCalculationError, NSRoundingMode};
+
+    let a = decimal(15, -1).decimalValue();
+    let b = decimal(25, -2).decimalValue();
+    assert_eq!(a.to_string(), "1.5");
+    assert_eq!((a + b).to_string(), "1.75");
+    assert_eq!((a - b).to_string(), "1.25");
+    assert_eq!((a * b).to_string(), "0.375");
+    assert_eq!((a / b).to_string(), "6");
+    assert_eq!((b - a).to_string(), "-1.25");
+
+    let sum = decimal(1, -1).decimalValue() + decimal(2, -1).decimalValue();
+    assert_eq!(sum.to_string(), "0.3");
+
+    let third = decimal(1, 0)
+        .decimalValue()
+        .checked_div(decimal(3, 0).decimalValue(), NSRoundingMode::RoundUp)
+        .unwrap();
+    assert!(third.to_string().ends_with('4'));
+
+    let zero = NSDecimalNumber::zero().decimalValue();
+    assert_eq!(
+        a.checked_div(zero, NSRoundingMode::RoundPlain),
+        Err(NSCalculationError::DivideByZero)
+    );
+
+    let max = NSDecimalNumber::maximumDecimalNumber().decimalValue();
+    assert_eq!(
+        max.checked_mul(decimal(10, 0).decimalValue(), NSRoundingMode::RoundPlain),
+        Err(NSCalculationError::Overflow)
+    );
+
+    let nan = NSDecimalNumber::notANumber().decimalValue();
+    assert!(nan.is_nan());
+    assert!((nan + a).is_nan());
+    assert!(!a.is_nan());
+}
+
+#[test]
+#[should_panic = "attempt to divide by zero"]
+#[cfg_attr(
+    feature = "gnustep-1-7",
+    ignore = "has different encoding, yet unsupported"
+)]
+fn test_decimal_div_by_zero_panics() {
+    let _ = decimal(1, 0).decimalValue() / NSDecimalNumber::zero().decimalValue();
+}
+
+#[test]
+#[cfg(feature = "NSString")]
+fn test_methods() {
+    use alloc::string::ToString;
+
+    use crate::NSString;
+
+    let from_str = |s: &str| NSDecimalNumber::decimalNumberWithString(Some(&NSString::from_str(s)));
+
+    let a = from_str("0.1");
+    let b = NSDecimalNumber::decimalNumberWithMantissa_exponent_isNegative(2, -1, false);
+    let sum = a.decimalNumberByAdding(&b);
+    // Unlike with floating point numbers, this is exact.
+    assert_eq!(sum.stringValue().to_string(), "0.3");
+    assert!(sum.isEqualToNumber(&from_str("0.3")));
+
+    let product = sum.decimalNumberByMultiplyingBy(&from_str("-10"));
+    assert_eq!(product.stringValue().to_string(), "-3");
+    assert_eq!(product.longLongValue(), -3);
+
+    let difference = product.decimalNumberBySubtracting(&a);
+    assert_eq!(difference.stringValue().to_string(), "-3.1");
+
+    // GNUStep has a different `NSDecimal` encoding, see above.
+    if cfg!(not(feature = "gnustep-1-7")) {
+        let decimal = difference.decimalValue();
+        let obj = NSDecimalNumber::initWithDecimal(NSDecimalNumber::alloc(), decimal);
+        assert!(obj.isEqualToNumber(&difference));
+    }
+}
+
+#[test]
+fn test_f64_roundtrip() {
+    use objc2::msg_send;
+    use objc2::rc::Retained;
+
+    let from_f64 = |value: f64| -> Retained<NSDecimalNumber> {
+        unsafe { msg_send![NSDecimalNumber::alloc(), initWithDouble: value] }
+    };
+
+    // Exactly representable values round-trip exactly.
+    for value in [0.0, 1.0, -1.0, 1.5, -2.25, 1024.125, 1e10] {
+        let number = from_f64(value);
+        assert_eq!(number.doubleValue(), value, "{number:?}");
+    }
+    assert_eq!(from_f64(1.5), decimal(15, -1));
+
+    // Other values round-trip approximately.
+    for value in [0.1, 0.3, 123.456, -98.765, 42.42] {
+        let number = from_f64(value);
+        let diff = (number.doubleValue() - value).abs();
+        assert!(diff <= value.abs() * 1e-15, "{value} != {number:?}");
+    }
+}
@@ -61,6 +61,38 @@ fn test_iter_objects() {
     assert_eq!(dict.objects().count(), 1);
 }
 
+#[test]
+#[cfg(feature = "NSEnumerator")]
+#[cfg(feature = "std")]
+fn test_iter_pairs() {
+    use crate::{NSCopying, NSMutableDictionary};
+    use std::collections::HashMap;
+    use std::string::String;
+
+    let expected: HashMap<String, String> = (0..20)
+        .map(|i| (format!("key{i}"), format!("value{i}")))
+        .collect();
+
+    let dict = NSMutableDictionary::new();
+    for (key, value) in &expected {
+        dict.insert(&*NSString::from_str(key), &*NSString::from_str(value));
+    }
+
+    let to_strings = |(key, value): (Retained<NSString>, Retained<NSString>)| {
+        (key.to_string(), value.to_string())
+    };
+    let borrowed: HashMap<String, String> = dict.iter().map(to_strings).collect();
+    assert_eq!(borrowed, expected);
+    assert_eq!((&*dict).into_iter().count(), 20);
+
+    let dict = dict.copy();
+    let owned: HashMap<String, String> = dict.into_iter().map(to_strings).collect();
+    assert_eq!(owned, expected);
+
+    let empty = NSDictionary::<NSString, NSString>::new();
+    assert_eq!(empty.iter().next(), None);
+}
+
 #[test]
 #[cfg(feature = "NSArray")]
 fn test_arrays() {
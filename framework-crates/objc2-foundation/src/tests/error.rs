@@ -27,3 +27,154 @@ fn custom_domain() {
     };
     assert_eq!(format!("{error}"), expected);
 }
+
+#[test]
+#[cfg(feature = "FoundationErrors")]
+#[cfg(all(feature = "NSArray", feature = "NSDictionary", feature = "NSObject"))]
+fn aggregate() {
+    use crate::{NSArray, NSMultipleUnderlyingErrorsKey, NSValidationMultipleErrorsError};
+
+    let errors = [
+        NSError::new(1, ns_string!("A")),
+        NSError::new(2, ns_string!("B")),
+        NSError::new(3, ns_string!("C")),
+    ];
+    let error = NSError::aggregate(&errors);
+    assert_eq!(&*error.domain(), unsafe { NSCocoaErrorDomain });
+    assert_eq!(error.code(), NSValidationMultipleErrorsError);
+
+    let expected = errors
+        .iter()
+        .map(|error| format!("{error}"))
+        .collect::<alloc::vec::Vec<_>>()
+        .join("\n");
+    assert_eq!(format!("{error}"), expected);
+
+    let user_info = error.userInfo();
+    let underlying = user_info
+        .objectForKey(unsafe { NSMultipleUnderlyingErrorsKey })
+        .unwrap()
+        .downcast::<NSArray>()
+        .unwrap();
+    assert_eq!(underlying.len(), 3);
+    for (underlying, error) in underlying.iter().zip(&errors) {
+        let underlying = underlying.downcast::<NSError>().unwrap();
+        assert_eq!(underlying.code(), error.code());
+        assert_eq!(underlying.domain(), error.domain());
+    }
+}
+
+#[test]
+#[cfg(feature = "FoundationErrors")]
+#[cfg(all(feature = "NSArray", feature = "NSDictionary", feature = "NSObject"))]
+fn underlying_errors() {
+    use objc2::runtime::AnyObject;
+    use objc2::AnyThread;
+
+    use crate::{NSDictionary, NSUnderlyingErrorKey};
+
+    let error = NSError::new(1, ns_string!("A"));
+    assert!(error.underlying_errors().is_empty());
+
+    let errors = [
+        NSError::new(2, ns_string!("B")),
+        NSError::new(3, ns_string!("C")),
+    ];
+    let aggregate = NSError::aggregate(&errors);
+    let underlying = aggregate.underlying_errors();
+    assert_eq!(underlying, errors);
+
+    let keys = [unsafe { NSUnderlyingErrorKey }];
+    let objects: [&AnyObject; 1] = [&aggregate];
+    let user_info = NSDictionary::from_slices(&keys, &objects);
+    let wrapper = unsafe {
+        NSError::initWithDomain_code_userInfo(
+            NSError::alloc(),
+            ns_string!("Wrapper"),
+            4,
+            Some(&user_info),
+        )
+    };
+    assert_eq!(wrapper.underlying_errors(), [aggregate.clone()]);
+
+    // Walk the full chain.
+    let mut codes = alloc::vec::Vec::new();
+    let mut queue = alloc::vec![wrapper];
+    while let Some(error) = queue.pop() {
+        codes.push(error.code());
+        queue.extend(error.underlying_errors().into_iter().rev());
+    }
+    assert_eq!(codes, [4, aggregate.code(), 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn question_mark() {
+    use objc2::rc::Retained;
+    use std::boxed::Box;
+    use std::error::Error;
+    use std::string::ToString;
+
+    fn fails(error: Retained<NSError>) -> Result<(), Box<dyn Error>> {
+        let res: Result<(), Retained<NSError>> = Err(error);
+        res?;
+        Ok(())
+    }
+
+    let error = NSError::new(42, ns_string!("MyDomain"));
+    let boxed = fails(error.clone()).unwrap_err();
+    assert_eq!(boxed.to_string(), error.to_string());
+    assert!(boxed.source().is_none());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn question_mark_send_sync() {
+    use objc2::rc::Retained;
+    use std::boxed::Box;
+    use std::error::Error;
+    use std::string::ToString;
+    use std::thread;
+
+    fn fails(error: Retained<NSError>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let res: Result<(), Retained<NSError>> = Err(error);
+        res?;
+        Ok(())
+    }
+
+    let error = NSError::new(42, ns_string!("MyDomain"));
+    let boxed = fails(error.clone()).unwrap_err();
+    // The boxed error can be sent to another thread.
+    let description = thread::spawn(move || boxed.to_string()).join().unwrap();
+    assert_eq!(description, error.to_string());
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(all(feature = "NSDictionary", feature = "NSObject"))]
+fn error_trait_description() {
+    use objc2::runtime::AnyObject;
+    use objc2::AnyThread;
+    use std::error::Error;
+    use std::string::ToString;
+
+    use crate::NSDictionary;
+
+    let keys = [NSError::NSLocalizedDescriptionKey()];
+    let objects: [&AnyObject; 1] = [ns_string!("Something went wrong")];
+    let user_info = NSDictionary::from_slices(&keys, &objects);
+    let error = unsafe {
+        NSError::initWithDomain_code_userInfo(
+            NSError::alloc(),
+            ns_string!("MyDomain"),
+            7,
+            Some(&user_info),
+        )
+    };
+    assert_eq!(&*error.domain(), ns_string!("MyDomain"));
+    assert_eq!(error.code(), 7);
+    assert_eq!(error.userInfo().len(), 1);
+
+    let error: &dyn Error = &*error;
+    assert_eq!(error.to_string(), "Something went wrong");
+}
@@ -43,3 +43,59 @@ fn unwrap() {
 }
 
 // Further tests in `tests::exception`
+
+#[test]
+#[cfg(feature = "std")]
+fn uncaught_exception_handler() {
+    use core::ptr::NonNull;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    static CALLED: AtomicBool = AtomicBool::new(false);
+
+    fn handler(exception: &NSException) {
+        assert_eq!(&*exception.name(), ns_string!("abc"));
+        CALLED.store(true, Ordering::Relaxed);
+    }
+
+    NSException::set_uncaught_exception_handler(handler);
+
+    // Actually letting an exception go uncaught would terminate the test
+    // process, so instead invoke the installed handler the same way that
+    // Foundation would.
+    let installed = unsafe { crate::NSGetUncaughtExceptionHandler() };
+    assert!(!installed.is_null());
+    let installed: unsafe extern "C-unwind" fn(NonNull<NSException>) =
+        unsafe { core::mem::transmute(installed) };
+
+    let exc = NSException::new(ns_string!("abc"), Some(ns_string!("def")), None).unwrap();
+    unsafe { installed(NonNull::from(&*exc)) };
+    assert!(CALLED.load(Ordering::Relaxed));
+}
+
+#[test]
+#[cfg(feature = "exception")]
+fn catch() {
+    use objc2::exception::Exception;
+    use objc2::rc::Retained;
+
+    let res = NSException::catch(|| 42);
+    assert_eq!(res.unwrap(), 42);
+
+    let exc = NSException::new(ns_string!("abc"), Some(ns_string!("def")), None).unwrap();
+    let res = NSException::catch(|| exc.raise());
+    let caught = res.unwrap_err();
+    assert_eq!(caught, exc);
+    assert_eq!(&*caught.name(), ns_string!("abc"));
+    assert_eq!(&*caught.reason().unwrap(), ns_string!("def"));
+
+    // Other objects are thrown again.
+    let obj = NSObject::new();
+    // SAFETY: Any object can be thrown.
+    let thrown: Retained<Exception> = unsafe { Retained::cast_unchecked(obj.clone()) };
+    let res = objc2::exception::catch(|| {
+        let _ = NSException::catch(|| objc2::exception::throw(thrown));
+        unreachable!();
+    });
+    let caught = res.unwrap_err().unwrap();
+    assert_eq!(Retained::as_ptr(&caught).cast(), Retained::as_ptr(&obj));
+}
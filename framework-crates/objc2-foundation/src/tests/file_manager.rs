@@ -0,0 +1,76 @@
+#![cfg(all(
+    feature = "NSArray",
+    feature = "NSError",
+    feature = "NSFileManager",
+    feature = "NSString",
+    feature = "NSURL",
+    feature = "std"
+))]
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+use crate::{NSFileManager, NSURL};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("objc2-{name}-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn exists_and_delete() {
+    let manager = NSFileManager::defaultManager();
+    let dir = temp_dir("exists-and-delete");
+    let path = dir.join("file.bin");
+    let url = NSURL::from_file_path(&path).unwrap();
+
+    assert!(!manager.item_exists(&url));
+    let err = manager.delete_item(&url).unwrap_err();
+    assert_eq!(err.domain().to_string(), "NSCocoaErrorDomain");
+
+    fs::write(&path, [0, 1, 2, 255]).unwrap();
+    assert!(manager.item_exists(&url));
+
+    manager.delete_item(&url).unwrap();
+    assert!(!manager.item_exists(&url));
+    assert!(!path.exists());
+
+    // Removes directories recursively.
+    let dir_url = NSURL::from_directory_path(&dir).unwrap();
+    fs::write(&path, [0, 1, 2, 255]).unwrap();
+    assert!(manager.item_exists(&dir_url));
+    manager.delete_item(&dir_url).unwrap();
+    assert!(!dir.exists());
+}
+
+#[test]
+fn list_directory() {
+    let manager = NSFileManager::defaultManager();
+    let dir = temp_dir("list-directory");
+    fs::write(dir.join("a.txt"), "a").unwrap();
+    fs::write(dir.join("b.txt"), "b").unwrap();
+    fs::create_dir(dir.join("sub")).unwrap();
+    fs::write(dir.join("sub").join("c.txt"), "c").unwrap();
+
+    let dir_url = NSURL::from_directory_path(&dir).unwrap();
+    let mut names: Vec<_> = manager
+        .list_directory(&dir_url)
+        .unwrap()
+        .iter()
+        .map(|url| url.lastPathComponent().unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["a.txt", "b.txt", "sub"]);
+
+    // Not a directory.
+    let file_url = NSURL::from_file_path(dir.join("a.txt")).unwrap();
+    assert!(manager.list_directory(&file_url).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(manager.list_directory(&dir_url).is_err());
+    assert!(!manager.item_exists(&dir_url));
+}
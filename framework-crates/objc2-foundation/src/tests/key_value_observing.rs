@@ -0,0 +1,105 @@
+#![cfg(all(
+    feature = "NSDictionary",
+    feature = "NSKeyValueObserving",
+    feature = "NSObject",
+    feature = "NSString",
+    feature = "NSValue",
+    feature = "std"
+))]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use std::sync::Mutex;
+
+use objc2::rc::Retained;
+use objc2::{define_class, msg_send, AnyThread, DefinedClass, Message};
+
+use crate::{ns_string, KvoChange, KvoObserver, NSKeyValueObservingOptions, NSNumber, NSObject};
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[ivars = RefCell<Option<Retained<NSNumber>>>]
+    struct Observable;
+
+    impl Observable {
+        #[unsafe(method_id(number))]
+        fn number(&self) -> Option<Retained<NSNumber>> {
+            self.ivars().borrow().clone()
+        }
+
+        #[unsafe(method(setNumber:))]
+        fn set_number(&self, number: Option<&NSNumber>) {
+            *self.ivars().borrow_mut() = number.map(|number| number.retain());
+        }
+    }
+);
+
+impl Observable {
+    fn new() -> Retained<Self> {
+        let this = Self::alloc().set_ivars(RefCell::new(None));
+        unsafe { msg_send![super(this), init] }
+    }
+
+    fn set(&self, value: i32) {
+        let number = NSNumber::new_i32(value);
+        let _: () = unsafe { msg_send![self, setNumber: &*number] };
+    }
+}
+
+type Changes = Arc<Mutex<Vec<(Option<i32>, Option<i32>, bool)>>>;
+
+fn observe(object: &Observable, options: NSKeyValueObservingOptions) -> (KvoObserver, Changes) {
+    let changes = Changes::default();
+    let changes_clone = Arc::clone(&changes);
+    let observer = unsafe {
+        KvoObserver::observe(
+            object,
+            ns_string!("number"),
+            options,
+            move |change: KvoChange<NSNumber>| {
+                changes_clone.lock().unwrap().push((
+                    change.old.map(|n| n.as_i32()),
+                    change.new.map(|n| n.as_i32()),
+                    change.is_prior,
+                ));
+            },
+        )
+    };
+    (observer, changes)
+}
+
+#[test]
+fn test_observe() {
+    let object = Observable::new();
+    let (observer, changes) = observe(
+        &object,
+        NSKeyValueObservingOptions::Old | NSKeyValueObservingOptions::New,
+    );
+
+    object.set(1);
+    object.set(2);
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [(None, Some(1), false), (Some(1), Some(2), false)]
+    );
+
+    drop(observer);
+    object.set(3);
+    assert_eq!(changes.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_observe_prior() {
+    let object = Observable::new();
+    object.set(1);
+    let (_observer, changes) = observe(
+        &object,
+        NSKeyValueObservingOptions::New | NSKeyValueObservingOptions::Prior,
+    );
+
+    object.set(2);
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [(None, None, true), (None, Some(2), false)]
+    );
+}
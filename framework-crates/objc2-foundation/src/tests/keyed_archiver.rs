@@ -0,0 +1,54 @@
+#![cfg(all(
+    feature = "NSArray",
+    feature = "NSData",
+    feature = "NSError",
+    feature = "NSKeyedArchiver",
+    feature = "NSObject",
+    feature = "NSString",
+    feature = "NSValue"
+))]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSArray, NSData, NSKeyedArchiver, NSKeyedUnarchiver, NSNumber, NSString};
+
+#[test]
+fn string() {
+    let string = NSString::from_str("hello ✨");
+    let data = NSKeyedArchiver::archive(&*string).unwrap();
+    let decoded = NSKeyedUnarchiver::unarchive::<NSString>(&data).unwrap();
+    assert_eq!(decoded, string);
+}
+
+#[test]
+fn number() {
+    let number = NSNumber::new_i64(-42);
+    let data = NSKeyedArchiver::archive(&*number).unwrap();
+    let decoded = NSKeyedUnarchiver::unarchive::<NSNumber>(&data).unwrap();
+    assert_eq!(decoded, number);
+}
+
+#[test]
+fn array() {
+    let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    let data = NSKeyedArchiver::archive(&*array).unwrap();
+    let decoded = NSKeyedUnarchiver::unarchive::<NSArray>(&data).unwrap();
+    let decoded = decoded.try_downcast_elements::<NSString>().unwrap();
+    assert_eq!(decoded, array);
+}
+
+#[test]
+fn wrong_class() {
+    let data = NSKeyedArchiver::archive(ns_string!("hello")).unwrap();
+    let err = NSKeyedUnarchiver::unarchive::<NSNumber>(&data).unwrap_err();
+    assert_eq!(err.domain().to_string(), "NSCocoaErrorDomain");
+
+    let data = NSKeyedArchiver::archive(&*NSNumber::new_i32(1)).unwrap();
+    assert!(NSKeyedUnarchiver::unarchive::<NSString>(&data).is_err());
+}
+
+#[test]
+fn invalid_data() {
+    let data = NSData::with_bytes(b"not an archive");
+    assert!(NSKeyedUnarchiver::unarchive::<NSString>(&data).is_err());
+    assert!(NSKeyedUnarchiver::unarchive::<NSString>(&NSData::new()).is_err());
+}
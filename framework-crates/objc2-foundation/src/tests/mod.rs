@@ -2,24 +2,36 @@ mod array;
 mod attributed_string;
 mod auto_traits;
 mod bundle;
+mod calendar;
 mod data;
+mod date;
 mod decimal_number;
 mod dictionary;
 mod error;
 mod exception;
+mod file_manager;
+mod key_value_observing;
+mod keyed_archiver;
 mod lock;
 mod measurement;
 mod mutable_array;
 mod mutable_data;
 mod mutable_dictionary;
+mod mutable_ordered_set;
 mod mutable_set;
 mod mutable_string;
+mod notification;
 mod number;
+mod predicate;
 mod process_info;
 mod proxy;
+mod regular_expression;
+mod run_loop;
 mod set;
 mod string;
 mod task;
 mod thread;
+mod url_session;
+mod user_defaults;
 mod uuid;
 mod value;
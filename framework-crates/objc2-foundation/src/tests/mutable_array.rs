@@ -111,6 +111,80 @@ fn test_sort() {
     assert_eq!(strings.objectAtIndex(1).to_string(), "hello");
 }
 
+#[test]
+#[cfg(all(feature = "NSObjCRuntime", feature = "NSValue"))]
+fn test_sort_numbers_descending() {
+    use crate::NSNumber;
+    use alloc::vec::Vec;
+
+    let numbers: Vec<_> = [3, 1, 4, 1, 5, 9, 2, 6]
+        .into_iter()
+        .map(NSNumber::new_i32)
+        .collect();
+    let array = NSMutableArray::from_retained_slice(&numbers);
+
+    array.sort_by(|a, b| b.as_i32().cmp(&a.as_i32()));
+    let values: Vec<i32> = array.to_vec().iter().map(|n| n.as_i32()).collect();
+    assert_eq!(values, [9, 6, 5, 4, 3, 2, 1, 1]);
+}
+
+#[test]
+#[cfg(all(feature = "NSObjCRuntime", feature = "NSValue"))]
+#[cfg(feature = "std")]
+fn test_sort_panic() {
+    use crate::NSNumber;
+    use alloc::vec::Vec;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let numbers: Vec<_> = (0..10).map(NSNumber::new_i32).collect();
+    let array = NSMutableArray::from_retained_slice(&numbers);
+
+    let mut calls_after_panic = 0;
+    let mut panicked = false;
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        array.sort_by(|a, b| {
+            if panicked {
+                calls_after_panic += 1;
+            }
+            if a.as_i32() == 5 || b.as_i32() == 5 {
+                panicked = true;
+                panic!("comparison failed");
+            }
+            b.as_i32().cmp(&a.as_i32())
+        });
+    }));
+    let payload = res.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"comparison failed"));
+
+    // The closure is not called again once it has panicked.
+    assert!(panicked);
+    assert_eq!(calls_after_panic, 0);
+
+    // The array is still usable.
+    assert_eq!(array.count(), 10);
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_insert_sorted_by() {
+    use crate::NSNumber;
+    use alloc::vec::Vec;
+
+    let array = NSMutableArray::new();
+    let compare = |a: &NSNumber, b: &NSNumber| a.as_i32().cmp(&b.as_i32());
+
+    assert_eq!(array.insert_sorted_by(&NSNumber::new_i32(5), compare), 0);
+    assert_eq!(array.insert_sorted_by(&NSNumber::new_i32(1), compare), 0);
+    assert_eq!(array.insert_sorted_by(&NSNumber::new_i32(9), compare), 2);
+    assert_eq!(array.insert_sorted_by(&NSNumber::new_i32(3), compare), 1);
+    // Equal elements are inserted after the existing ones.
+    assert_eq!(array.insert_sorted_by(&NSNumber::new_i32(5), compare), 4);
+    assert_eq!(array.insert_sorted_by(&NSNumber::new_i32(7), compare), 5);
+
+    let values: Vec<i32> = array.to_vec().iter().map(|n| n.as_i32()).collect();
+    assert_eq!(values, [1, 3, 5, 5, 7, 9]);
+}
+
 #[test]
 #[cfg(feature = "NSValue")]
 #[cfg_attr(
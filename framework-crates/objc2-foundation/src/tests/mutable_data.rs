@@ -9,6 +9,25 @@ fn test_bytes_mut() {
     assert_eq!(data.to_vec(), [3, 16]);
 }
 
+#[test]
+fn test_bytes_mut_in_place() {
+    let original = [0x00, 0x0f, 0xf0, 0xff, 0x5a];
+    let data = NSMutableData::with_bytes(&original);
+    for byte in unsafe { data.as_mut_bytes_unchecked() } {
+        *byte ^= 0xa5;
+    }
+    assert_eq!(data.to_vec(), [0xa5, 0xaa, 0x55, 0x5a, 0xff]);
+
+    // XOR-ing again restores the original.
+    for byte in unsafe { data.as_mut_bytes_unchecked() } {
+        *byte ^= 0xa5;
+    }
+    assert_eq!(data.to_vec(), original);
+
+    let empty = NSMutableData::new();
+    assert!(unsafe { empty.as_mut_bytes_unchecked() }.is_empty());
+}
+
 #[test]
 fn test_set_len() {
     let data = NSMutableData::with_bytes(&[7, 16]);
@@ -71,3 +90,31 @@ fn test_extend() {
     (&data).extend(&*NSData::with_bytes(&[6, 7]));
     assert_eq!(data.to_vec(), &[1, 2, 3, 4, 5, 6, 7]);
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn test_write() {
+    use std::io::{self, Write};
+
+    let mut data = NSMutableData::new();
+    let mut src: &[u8] = &[1, 2, 3, 4, 5];
+    assert_eq!(io::copy(&mut src, &mut data).unwrap(), 5);
+    assert_eq!(data.to_vec(), [1, 2, 3, 4, 5]);
+
+    write!(data, "{}", 67).unwrap();
+    (&*data).write_all(b"!").unwrap();
+    data.flush().unwrap();
+    assert_eq!(data.to_vec(), [1, 2, 3, 4, 5, b'6', b'7', b'!']);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_copy_from_reader() {
+    use std::io;
+
+    let src = NSData::with_bytes(b"hello world");
+    let data = NSMutableData::with_bytes(b">");
+    // Writing through a shared reference to the `Retained`.
+    assert_eq!(io::copy(&mut src.reader(), &mut &data).unwrap(), 11);
+    assert_eq!(data.to_vec(), b">hello world");
+}
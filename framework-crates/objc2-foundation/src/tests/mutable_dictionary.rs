@@ -1,6 +1,8 @@
 #![cfg(feature = "NSDictionary")]
 #![cfg(feature = "NSValue")]
 #![cfg(feature = "NSObject")]
+use alloc::vec::Vec;
+
 use objc2::rc::Retained;
 
 use crate::{NSMutableDictionary, NSNumber, NSObject};
@@ -86,6 +88,38 @@ fn test_remove() {
     assert_eq!(dict.len(), 1);
 }
 
+#[test]
+#[cfg(all(feature = "NSArray", feature = "NSEnumerator"))]
+fn test_remove_and_retain_keys() {
+    use crate::NSArray;
+    use alloc::vec::Vec;
+
+    fn sorted_keys(dict: &NSMutableDictionary<NSNumber, NSObject>) -> Vec<i32> {
+        let mut keys: Vec<_> = dict.allKeys().iter().map(|key| key.as_i32()).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    let dict = sample_dict();
+    dict.removeObjectsForKeys(&NSArray::from_retained_slice(&[
+        NSNumber::new_i32(1),
+        NSNumber::new_i32(3),
+        NSNumber::new_i32(4),
+    ]));
+    assert_eq!(sorted_keys(&dict), [2]);
+
+    let dict = sample_dict();
+    dict.retain_keys(&NSArray::from_retained_slice(&[
+        NSNumber::new_i32(1),
+        NSNumber::new_i32(3),
+        NSNumber::new_i32(4),
+    ]));
+    assert_eq!(sorted_keys(&dict), [1, 3]);
+
+    dict.retain_keys(&NSArray::new());
+    assert!(dict.is_empty());
+}
+
 #[test]
 fn test_clear() {
     let dict = sample_dict();
@@ -115,3 +149,27 @@ fn test_iter_mutation_detection() {
 
     let _ = iter.next();
 }
+
+#[test]
+#[cfg(feature = "NSEnumerator")]
+fn test_iter_pairs() {
+    let dict = sample_dict();
+    let mut keys = Vec::new();
+    for (key, obj) in &*dict {
+        assert_eq!(dict.objectForKey(&key).unwrap(), obj);
+        keys.push(key.as_i32());
+    }
+    keys.sort_unstable();
+    assert_eq!(keys, [1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "NSEnumerator")]
+#[should_panic = "mutation detected during enumeration"]
+fn test_iter_pairs_mutation_detection() {
+    let dict = sample_dict();
+
+    for (key, _) in &*dict {
+        dict.removeObjectForKey(&key);
+    }
+}
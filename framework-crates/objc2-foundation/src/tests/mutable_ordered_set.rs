@@ -0,0 +1,25 @@
+#![cfg(feature = "NSOrderedSet")]
+#![cfg(feature = "NSValue")]
+use alloc::vec::Vec;
+
+use crate::{NSMutableOrderedSet, NSNumber};
+
+#[test]
+fn test_insert_sorted_by() {
+    let set = NSMutableOrderedSet::<NSNumber>::new();
+    let insert =
+        |n| set.insert_sorted_by(&NSNumber::new_i32(n), |a, b| a.as_i32().cmp(&b.as_i32()));
+
+    assert_eq!(insert(5), Some(0));
+    assert_eq!(insert(1), Some(0));
+    assert_eq!(insert(9), Some(2));
+    assert_eq!(insert(3), Some(1));
+    assert_eq!(insert(7), Some(3));
+    // Already present.
+    assert_eq!(insert(5), None);
+
+    let values: Vec<i32> = (0..set.count())
+        .map(|i| set.objectAtIndex(i).as_i32())
+        .collect();
+    assert_eq!(values, [1, 3, 5, 7, 9]);
+}
@@ -29,6 +29,43 @@ fn test_append() {
     assert_eq!(&s.to_string(), "abcdefghi");
 }
 
+#[test]
+fn test_push_insert_replace() {
+    let s = NSMutableString::from_str("Hello");
+    s.push_str("!");
+    s.insert_str(5, ", world");
+    assert_eq!(s.to_string(), "Hello, world!");
+
+    // Offsets are in UTF-16 code units, and "😀" takes up two of those.
+    s.insert_str(0, "😀 ");
+    assert_eq!(s.length(), 16);
+    s.insert_str(s.length(), "");
+    assert_eq!(s.to_string(), "😀 Hello, world!");
+
+    #[cfg(feature = "NSRange")]
+    {
+        s.replace_range(10..15, "Rust");
+        assert_eq!(s.to_string(), "😀 Hello, Rust!");
+        s.replace_range(0..3, "");
+        assert_eq!(s.to_string(), "Hello, Rust!");
+    }
+}
+
+#[test]
+#[should_panic = "index 4 out of bounds of string with length 3"]
+fn test_insert_out_of_bounds() {
+    let s = NSMutableString::from_str("abc");
+    s.insert_str(4, "d");
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+#[should_panic = "range 2..4 out of bounds of string with length 3"]
+fn test_replace_range_out_of_bounds() {
+    let s = NSMutableString::from_str("abc");
+    s.replace_range(2..4, "d");
+}
+
 #[test]
 fn test_set() {
     let s = NSMutableString::from_str("abc");
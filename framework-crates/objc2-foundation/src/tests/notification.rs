@@ -0,0 +1,50 @@
+#![cfg(all(
+    feature = "NSNotification",
+    feature = "NSOperation",
+    feature = "NSString",
+    feature = "block2"
+))]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{ns_string, NSNotificationCenter, NSObject, NotificationObserver};
+
+#[test]
+fn test_observer_removed_on_drop() {
+    let center = NSNotificationCenter::defaultCenter();
+    let name = ns_string!("ObserverRemovedOnDrop");
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let count_clone = Arc::clone(&count);
+    let observer = NotificationObserver::add(&center, Some(name), None, None, move |n| {
+        assert_eq!(&*n.name(), name);
+        count_clone.fetch_add(1, Ordering::Relaxed);
+    });
+
+    unsafe { center.postNotificationName_object(name, None) };
+    unsafe { center.postNotificationName_object(ns_string!("OtherName"), None) };
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+
+    drop(observer);
+    unsafe { center.postNotificationName_object(name, None) };
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_observer_object() {
+    let center = NSNotificationCenter::defaultCenter();
+    let name = ns_string!("ObserverObject");
+    let sender = NSObject::new();
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let count_clone = Arc::clone(&count);
+    let _observer =
+        NotificationObserver::add(&center, Some(name), Some(&**sender), None, move |_| {
+            count_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+    unsafe { center.postNotificationName_object(name, Some(&**sender)) };
+    unsafe { center.postNotificationName_object(name, Some(&**NSObject::new())) };
+    unsafe { center.postNotificationName_object(name, None) };
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
@@ -75,6 +75,18 @@ fn cast_between_types() {
     assert_eq!(NSNumber::new_f32(1.0).as_u32(), 1);
 }
 
+#[test]
+fn lossy_coercion() {
+    assert_eq!(NSNumber::new_i32(42).as_f64(), 42.0);
+    assert_eq!(NSNumber::new_u64(1 << 53).as_f64(), 9007199254740992.0);
+    assert_eq!(NSNumber::new_bool(true).as_f64(), 1.0);
+
+    // Floating point values are truncated towards zero.
+    assert_eq!(NSNumber::new_f64(3.99).as_i64(), 3);
+    assert_eq!(NSNumber::new_f64(-3.99).as_i64(), -3);
+    assert_eq!(NSNumber::new_f32(0.5).as_i64(), 0);
+}
+
 #[test]
 fn equality() {
     let val1 = NSNumber::new_u32(123);
@@ -129,3 +141,170 @@ fn display_debug() {
     assert_display_debug(NSNumber::new_bool(true), "1");
     assert_display_debug(NSNumber::new_bool(false), "0");
 }
+
+#[test]
+fn classify() {
+    let int = NSNumber::new_i32(-42);
+    assert!(int.is_integer());
+    assert!(!int.is_float());
+    assert!(!int.is_boolean());
+
+    let float = NSNumber::new_f64(4.2);
+    assert!(!float.is_integer());
+    assert!(float.is_float());
+    assert!(!float.is_boolean());
+
+    let float = NSNumber::new_f32(1.0);
+    assert!(float.is_float());
+
+    for value in [true, false] {
+        let boolean = NSNumber::new_bool(value);
+        assert!(boolean.is_boolean());
+        assert!(boolean.is_integer());
+        assert!(!boolean.is_float());
+    }
+}
+
+#[test]
+fn kind() {
+    use crate::NSNumberKind;
+
+    assert_eq!(NSNumber::new_i8(-1).kind(), NSNumberKind::Signed);
+    assert_eq!(NSNumber::new_i64(i64::MIN).kind(), NSNumberKind::Signed);
+    assert_eq!(NSNumber::new_u64(u64::MAX).kind(), NSNumberKind::Unsigned);
+    assert_eq!(NSNumber::new_f32(1.0).kind(), NSNumberKind::Float);
+    assert_eq!(NSNumber::new_f64(1.5).kind(), NSNumberKind::Float);
+    assert_eq!(NSNumber::new_bool(false).kind(), NSNumberKind::Bool);
+}
+
+#[test]
+fn try_from() {
+    let int = |n: &NSNumber| i64::try_from(n).ok();
+    let uint = |n: &NSNumber| u64::try_from(n).ok();
+    let float = |n: &NSNumber| f64::try_from(n).ok();
+    let boolean = |n: &NSNumber| bool::try_from(n).ok();
+
+    let min = NSNumber::new_i64(i64::MIN);
+    assert_eq!(int(&min), Some(i64::MIN));
+    assert_eq!(uint(&min), None);
+    assert_eq!(float(&min), Some(-9_223_372_036_854_775_808.0));
+
+    let max = NSNumber::new_i64(i64::MAX);
+    assert_eq!(int(&max), Some(i64::MAX));
+    assert_eq!(uint(&max), Some(i64::MAX as u64));
+    assert_eq!(float(&max), None);
+
+    let max = NSNumber::new_u64(u64::MAX);
+    assert_eq!(int(&max), None);
+    assert_eq!(uint(&max), Some(u64::MAX));
+    assert_eq!(float(&max), None);
+
+    let negative = NSNumber::new_i32(-42);
+    assert_eq!(int(&negative), Some(-42));
+    assert_eq!(uint(&negative), None);
+    assert_eq!(float(&negative), Some(-42.0));
+    assert_eq!(boolean(&negative), None);
+
+    let fraction = NSNumber::new_f64(4.5);
+    assert_eq!(int(&fraction), None);
+    assert_eq!(uint(&fraction), None);
+    assert_eq!(float(&fraction), Some(4.5));
+
+    let integral = NSNumber::new_f32(-3.0);
+    assert_eq!(int(&integral), Some(-3));
+    assert_eq!(uint(&integral), None);
+    assert_eq!(float(&integral), Some(-3.0));
+
+    let out_of_range = NSNumber::new_f64(1e19);
+    assert_eq!(int(&out_of_range), None);
+    assert_eq!(uint(&out_of_range), Some(10_000_000_000_000_000_000));
+
+    assert_eq!(
+        float(&NSNumber::new_f64(f64::NAN)).map(f64::is_nan),
+        Some(true)
+    );
+    assert_eq!(int(&NSNumber::new_f64(f64::NAN)), None);
+    assert_eq!(int(&NSNumber::new_f64(f64::INFINITY)), None);
+
+    let yes = NSNumber::new_bool(true);
+    assert_eq!(boolean(&yes), Some(true));
+    assert_eq!(int(&yes), None);
+    assert_eq!(boolean(&NSNumber::new_u8(1)), None);
+}
+
+#[test]
+fn try_from_error() {
+    use alloc::string::ToString;
+
+    let err = u64::try_from(&*NSNumber::new_i8(-1)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "stored signed integer cannot be represented in the target type"
+    );
+}
+
+#[test]
+fn arithmetic() {
+    use crate::NSNumberKind;
+
+    let sum = &*NSNumber::new_i32(-2) + &*NSNumber::new_u8(3);
+    assert_eq!(sum.as_i64(), 1);
+    assert_eq!(sum.kind(), NSNumberKind::Signed);
+
+    let diff = &*NSNumber::new_u32(2) - &*NSNumber::new_u32(3);
+    assert_eq!(diff.as_i64(), -1);
+
+    let product = &*NSNumber::new_i64(-4) * &*NSNumber::new_bool(true);
+    assert_eq!(product.as_i64(), -4);
+
+    // Integer division truncates.
+    let quotient = &*NSNumber::new_i32(-7) / &*NSNumber::new_i32(2);
+    assert_eq!(quotient.as_i64(), -3);
+
+    // Results that don't fit in `i64` are stored as `u64`.
+    let sum = &*NSNumber::new_i64(i64::MAX) + &*NSNumber::new_i64(1);
+    assert_eq!(sum.kind(), NSNumberKind::Unsigned);
+    assert_eq!(sum.as_u64(), i64::MAX as u64 + 1);
+    let diff = &*NSNumber::new_u64(u64::MAX) - &*NSNumber::new_u64(u64::MAX);
+    assert_eq!(diff.as_i64(), 0);
+
+    // Mixing with floating point values.
+    let sum = &*NSNumber::new_i32(1) + &*NSNumber::new_f32(0.5);
+    assert_eq!(sum.kind(), NSNumberKind::Float);
+    assert_eq!(sum.as_f64(), 1.5);
+    let quotient = &*NSNumber::new_i32(7) / &*NSNumber::new_f64(2.0);
+    assert_eq!(quotient.as_f64(), 3.5);
+    let quotient = &*NSNumber::new_f64(1.0) / &*NSNumber::new_i32(0);
+    assert_eq!(quotient.as_f64(), f64::INFINITY);
+
+    // The result compares equal to numbers of other types.
+    assert_eq!(sum, NSNumber::new_f64(1.5));
+    assert_eq!(
+        &*NSNumber::new_f32(0.5) * &*NSNumber::new_i32(4),
+        NSNumber::new_u8(2)
+    );
+}
+
+#[test]
+#[should_panic = "attempt to add with overflow"]
+fn add_overflow() {
+    let _ = &*NSNumber::new_u64(u64::MAX) + &*NSNumber::new_u64(1);
+}
+
+#[test]
+#[should_panic = "attempt to subtract with overflow"]
+fn sub_overflow() {
+    let _ = &*NSNumber::new_i64(i64::MIN) - &*NSNumber::new_u64(1);
+}
+
+#[test]
+#[should_panic = "attempt to multiply with overflow"]
+fn mul_overflow() {
+    let _ = &*NSNumber::new_i64(i64::MIN) * &*NSNumber::new_i64(2);
+}
+
+#[test]
+#[should_panic = "attempt to divide by zero"]
+fn div_by_zero() {
+    let _ = &*NSNumber::new_i32(1) / &*NSNumber::new_i32(0);
+}
@@ -0,0 +1,109 @@
+#![cfg(all(
+    feature = "NSArray",
+    feature = "NSComparisonPredicate",
+    feature = "NSCompoundPredicate",
+    feature = "NSDictionary",
+    feature = "NSExpression",
+    feature = "NSPredicate",
+    feature = "NSString",
+    feature = "NSValue"
+))]
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{ns_string, NSArray, NSDictionary, NSNumber, NSPredicate, NSString};
+
+type Person = NSDictionary<NSString, AnyObject>;
+
+fn people() -> Retained<NSArray<Person>> {
+    let person = |name: &str, age: i32| {
+        let name = NSString::from_str(name);
+        let age = NSNumber::new_i32(age);
+        let values: [&AnyObject; 2] = [&name, &age];
+        Person::from_slices(&[ns_string!("name"), ns_string!("age")], &values)
+    };
+    NSArray::from_retained_slice(&[
+        person("Alice", 30),
+        person("Bob", 25),
+        person("Carol", 35),
+        person("Dave", 25),
+    ])
+}
+
+fn filter(predicate: &NSPredicate) -> Vec<String> {
+    people()
+        .filteredArrayUsingPredicate(predicate)
+        .to_vec()
+        .iter()
+        .map(|person| {
+            let name = person.objectForKey(ns_string!("name")).unwrap();
+            name.downcast::<NSString>().unwrap().to_string()
+        })
+        .collect()
+}
+
+fn age(age: i32) -> Retained<NSNumber> {
+    NSNumber::new_i32(age)
+}
+
+#[test]
+fn comparison() {
+    let key = ns_string!("age");
+    assert_eq!(
+        filter(&NSPredicate::key_equals(key, &age(25))),
+        ["Bob", "Dave"]
+    );
+    assert_eq!(
+        filter(&NSPredicate::key_greater_than(key, &age(25))),
+        ["Alice", "Carol"]
+    );
+    assert_eq!(
+        filter(&NSPredicate::key_less_than(key, &age(30))),
+        ["Bob", "Dave"]
+    );
+    assert_eq!(
+        filter(&NSPredicate::key_equals(key, &age(99))),
+        [] as [&str; 0]
+    );
+}
+
+#[test]
+fn contains_and_in() {
+    let name = ns_string!("name");
+    assert_eq!(
+        filter(&NSPredicate::key_contains(name, ns_string!("a"))),
+        ["Carol", "Dave"]
+    );
+    // Case-sensitive.
+    assert_eq!(
+        filter(&NSPredicate::key_contains(name, ns_string!("A"))),
+        ["Alice"]
+    );
+
+    let names = NSArray::from_slice(&[ns_string!("Bob"), ns_string!("Carol"), ns_string!("Eve")]);
+    assert_eq!(filter(&NSPredicate::key_in(name, &names)), ["Bob", "Carol"]);
+}
+
+#[test]
+fn compound() {
+    let young = NSPredicate::key_less_than(ns_string!("age"), &age(30));
+    let has_o = NSPredicate::key_contains(ns_string!("name"), ns_string!("o"));
+
+    assert_eq!(filter(&NSPredicate::and(&[&young, &has_o])), ["Bob"]);
+    assert_eq!(
+        filter(&NSPredicate::or(&[&young, &has_o])),
+        ["Bob", "Carol", "Dave"]
+    );
+    assert_eq!(filter(&NSPredicate::not(&young)), ["Alice", "Carol"]);
+    assert_eq!(
+        filter(&NSPredicate::not(&NSPredicate::or(&[&young, &has_o]))),
+        ["Alice"]
+    );
+
+    // Empty compound predicates.
+    assert_eq!(filter(&NSPredicate::and(&[])).len(), 4);
+    assert_eq!(filter(&NSPredicate::or(&[])).len(), 0);
+}
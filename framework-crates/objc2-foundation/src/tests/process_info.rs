@@ -25,3 +25,15 @@ fn encoding_operating_system_version() {
     let info = NSProcessInfo::processInfo();
     let _version = info.operatingSystemVersion();
 }
+
+#[test]
+fn system_info() {
+    let info = NSProcessInfo::processInfo();
+
+    #[cfg(feature = "NSDate")]
+    assert!(!info.system_uptime().is_zero());
+    assert!(info.physical_memory() > 0);
+    assert!(info.processor_count() > 0);
+    assert!(info.active_processor_count() > 0);
+    assert!(info.active_processor_count() <= info.processor_count());
+}
@@ -0,0 +1,119 @@
+#![cfg(all(
+    feature = "NSArray",
+    feature = "NSError",
+    feature = "NSRange",
+    feature = "NSRegularExpression",
+    feature = "NSString",
+    feature = "NSTextCheckingResult"
+))]
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+
+use crate::{
+    ns_string, NSMatchingOptions, NSRange, NSRegularExpression, NSRegularExpressionOptions,
+    NSString,
+};
+
+fn regex(pattern: &NSString) -> Retained<NSRegularExpression> {
+    NSRegularExpression::regularExpressionWithPattern_options_error(
+        pattern,
+        NSRegularExpressionOptions::empty(),
+    )
+    .unwrap()
+}
+
+fn to_strings(captures: Vec<Option<Retained<NSString>>>) -> Vec<Option<String>> {
+    captures
+        .into_iter()
+        .map(|c| c.map(|c| c.to_string()))
+        .collect()
+}
+
+#[test]
+fn is_match() {
+    let regex = regex(ns_string!("^a+b$"));
+    assert!(regex.is_match(ns_string!("aab")));
+    assert!(!regex.is_match(ns_string!("aabc")));
+    assert!(!regex.is_match(ns_string!("")));
+}
+
+#[test]
+fn first_match() {
+    let regex = regex(ns_string!(r"(\d+)-(\d+)"));
+    assert_eq!(regex.first_match_captures(ns_string!("none")), None);
+
+    let captures = regex.first_match_captures(ns_string!("1-2 3-4")).unwrap();
+    assert_eq!(
+        to_strings(captures),
+        [Some("1-2".into()), Some("1".into()), Some("2".into())]
+    );
+}
+
+#[test]
+fn all_matches() {
+    let regex = regex(ns_string!(r"(\w)(\d)?"));
+    let matches: Vec<_> = regex
+        .all_captures(ns_string!("a1 b c3"))
+        .into_iter()
+        .map(to_strings)
+        .collect();
+    assert_eq!(
+        matches,
+        [
+            [Some("a1".into()), Some("a".into()), Some("1".into())],
+            [Some("b".into()), Some("b".into()), None],
+            [Some("c3".into()), Some("c".into()), Some("3".into())],
+        ]
+    );
+
+    assert!(regex.all_captures(ns_string!("")).is_empty());
+}
+
+#[test]
+fn nested_and_named_groups() {
+    let regex = regex(ns_string!(r"(?<date>(?<year>\d{4})-(\d{2}))"));
+    let s = ns_string!("on 2024-05!");
+    let captures = regex.first_match_captures(s).unwrap();
+    assert_eq!(
+        to_strings(captures),
+        [
+            Some("2024-05".into()),
+            Some("2024-05".into()),
+            Some("2024".into()),
+            Some("05".into()),
+        ]
+    );
+
+    // Named groups can still be looked up by name on the raw result.
+    let result = regex
+        .firstMatchInString_options_range(
+            s,
+            NSMatchingOptions::empty(),
+            NSRange::from(0..s.length()),
+        )
+        .unwrap();
+    assert_eq!(
+        result.rangeWithName(ns_string!("year")),
+        NSRange::from(3..7)
+    );
+}
+
+#[test]
+fn non_ascii() {
+    // Characters outside the BMP take up two UTF-16 code units, and
+    // multiple bytes in UTF-8.
+    let regex = regex(ns_string!(r"(\S+) (\S+)"));
+    let captures = regex
+        .first_match_captures(ns_string!("🦀😀 日本語 ü"))
+        .unwrap();
+    assert_eq!(
+        to_strings(captures),
+        [
+            Some("🦀😀 日本語".into()),
+            Some("🦀😀".into()),
+            Some("日本語".into()),
+        ]
+    );
+}
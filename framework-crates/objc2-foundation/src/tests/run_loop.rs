@@ -0,0 +1,70 @@
+#![cfg(feature = "NSRunLoop")]
+#![cfg(feature = "NSDate")]
+use core::cell::Cell;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{define_class, msg_send, sel, AnyThread, DefinedClass};
+
+use crate::{NSDate, NSObject, NSObjectNSDelayedPerforming, NSRunLoop};
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[ivars = Cell<u32>]
+    struct DelayedTarget;
+
+    impl DelayedTarget {
+        #[unsafe(method(fire:))]
+        fn fire(&self, _arg: Option<&AnyObject>) {
+            self.ivars().set(self.ivars().get() + 1);
+        }
+    }
+);
+
+impl DelayedTarget {
+    fn new() -> Retained<Self> {
+        let this = Self::alloc().set_ivars(Cell::new(0));
+        unsafe { msg_send![super(this), init] }
+    }
+
+    fn fired(&self) -> u32 {
+        self.ivars().get()
+    }
+}
+
+/// Run the current thread's run loop until the given number of seconds have
+/// passed, or until there is nothing more to run.
+fn run_for(seconds: f64) {
+    NSRunLoop::currentRunLoop().runUntilDate(&NSDate::dateWithTimeIntervalSinceNow(seconds));
+}
+
+#[test]
+fn perform_after_delay() {
+    let target = DelayedTarget::new();
+    // SAFETY: `fire:` takes a single (nullable) object argument.
+    unsafe { target.performSelector_withObject_afterDelay(sel!(fire:), None, 0.05) };
+
+    // Not performed until the run loop runs, and the delay has passed.
+    assert_eq!(target.fired(), 0);
+    run_for(0.0);
+    assert_eq!(target.fired(), 0);
+
+    run_for(1.0);
+    assert_eq!(target.fired(), 1);
+
+    // Only performed once.
+    run_for(0.1);
+    assert_eq!(target.fired(), 1);
+}
+
+#[test]
+fn cancel_perform_after_delay() {
+    let target = DelayedTarget::new();
+    // SAFETY: Same as above.
+    unsafe { target.performSelector_withObject_afterDelay(sel!(fire:), None, 0.05) };
+
+    // SAFETY: The target is a valid object.
+    unsafe { NSObject::cancelPreviousPerformRequestsWithTarget(&target) };
+    run_for(0.2);
+    assert_eq!(target.fired(), 0);
+}
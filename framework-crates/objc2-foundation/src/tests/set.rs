@@ -118,6 +118,55 @@ fn test_intersection() {
     assert!(!set2.intersectsSet(&set3));
 }
 
+#[test]
+fn test_set_algebra() {
+    let set1 = NSSet::from_slice(&[ns_string!("one"), ns_string!("two"), ns_string!("three")]);
+    let set2 = NSSet::from_slice(&[ns_string!("three"), ns_string!("four")]);
+    let empty = NSSet::<NSString>::new();
+
+    let union = set1.union(&set2);
+    assert_eq!(union.len(), 4);
+    assert!(set1.is_subset(&union));
+    assert!(set2.is_subset(&union));
+
+    let intersection = set1.intersection(&set2);
+    assert_eq!(intersection.len(), 1);
+    assert!(intersection.containsObject(ns_string!("three")));
+
+    let difference = set1.difference(&set2);
+    assert_eq!(difference.len(), 2);
+    assert!(!difference.containsObject(ns_string!("three")));
+    assert_eq!(set2.difference(&set1).len(), 1);
+
+    // The original sets are left untouched.
+    assert_eq!(set1.len(), 3);
+    assert_eq!(set2.len(), 2);
+
+    assert_eq!(set1.union(&empty), set1);
+    assert!(set1.intersection(&empty).is_empty());
+    assert_eq!(set1.difference(&empty), set1);
+    assert!(empty.is_subset(&set1));
+    assert!(!set1.is_subset(&set2));
+}
+
+#[test]
+#[cfg(feature = "NSObject")]
+fn test_set_algebra_retains() {
+    use objc2::rc::Retained;
+
+    let obj1 = NSObject::new();
+    let obj2 = NSObject::new();
+    let set1 = NSSet::from_slice(&[&*obj1, &*obj2]);
+    let set2 = NSSet::from_slice(&[&*obj2]);
+
+    let difference = set1.difference(&set2);
+    drop(set1);
+    drop(set2);
+    assert_eq!(difference.len(), 1);
+    let remaining = difference.anyObject().unwrap();
+    assert_eq!(Retained::as_ptr(&remaining), Retained::as_ptr(&obj1));
+}
+
 #[test]
 #[cfg(feature = "NSArray")]
 fn test_to_array() {
@@ -154,6 +203,33 @@ fn test_into_vec() {
     assert_eq!(set.to_vec().len(), 3);
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn test_hash_set() {
+    use alloc::vec::Vec;
+    use std::collections::HashSet;
+
+    use objc2::rc::Retained;
+
+    let strs = ["one", "two", "three"].map(NSString::from_str);
+    let set = NSSet::from_retained_slice(&strs);
+    let hash_set = set.to_hash_set();
+    assert_eq!(hash_set.len(), 3);
+    assert!(strs.iter().all(|s| hash_set.contains(s)));
+
+    let set_again: Retained<NSSet<NSString>> = hash_set.into_iter().collect();
+    assert_eq!(set_again, set);
+
+    let nums = NSSet::from_retained_slice(&[1, 2, 2, 3].map(NSNumber::new_i32));
+    let hash_set = HashSet::from(&*nums);
+    let mut values: Vec<i32> = hash_set.iter().map(|n| n.as_i32()).collect();
+    values.sort();
+    assert_eq!(values, [1, 2, 3]);
+
+    let empty = NSSet::<NSString>::new();
+    assert!(HashSet::from(&*empty).is_empty());
+}
+
 #[test]
 fn test_equality() {
     let set1 = NSSet::<NSObject>::new();
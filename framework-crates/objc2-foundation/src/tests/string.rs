@@ -18,6 +18,22 @@ fn test_equality() {
     assert_ne!(s1, s3);
 }
 
+#[test]
+fn test_equality_heterogeneous() {
+    use objc2::rc::Retained;
+    use objc2::runtime::AnyObject;
+
+    let s = NSString::from_str("abc");
+    let obj: Retained<AnyObject> = NSString::from_str("abc").into();
+    assert!(s.isEqual(Some(&*obj)));
+    let other: Retained<AnyObject> = NSString::from_str("def").into();
+    assert!(!s.isEqual(Some(&*other)));
+    assert!(!s.isEqual(None));
+
+    let obj: Retained<AnyObject> = crate::NSObject::new().into();
+    assert!(!s.isEqual(Some(&*obj)));
+}
+
 #[test]
 fn display_debug() {
     let s = NSString::from_str("xyz\"123");
@@ -25,6 +41,19 @@ fn display_debug() {
     assert_eq!(format!("{s:?}"), r#""xyz\"123""#);
 }
 
+#[test]
+fn display_unicode() {
+    let strings = ["😀👨‍👩‍👧‍👦🇩🇰", "中文字符", "日本語", "한국어", "a\0b\0"];
+    for expected in strings {
+        let s = NSString::from_str(expected);
+        assert_eq!(format!("{s}"), expected);
+    }
+
+    // Formatting options are forwarded to `str`'s `Display` implementation.
+    let s = NSString::from_str("中文");
+    assert_eq!(format!("{s:>4}|{s:-<3}|{s:.1}"), "  中文|中文-|中");
+}
+
 #[test]
 fn test_empty() {
     let s1 = NSString::from_str("");
@@ -165,6 +194,77 @@ fn test_prefix_suffix() {
     assert!(!s.hasSuffix(&prefix));
 }
 
+#[test]
+fn test_lenient_parsing() {
+    assert_eq!(ns_string!(" 42abc").to_integer(), 42);
+    assert_eq!(ns_string!("-7").to_integer(), -7);
+    assert_eq!(ns_string!("abc").to_integer(), 0);
+    assert_eq!(ns_string!("").to_integer(), 0);
+
+    assert_eq!(ns_string!("\t2.5 meters").to_double(), 2.5);
+    assert_eq!(ns_string!("-1e3").to_double(), -1000.0);
+    assert_eq!(ns_string!("none").to_double(), 0.0);
+
+    assert!(ns_string!("yes").to_bool());
+    assert!(ns_string!("True").to_bool());
+    assert!(ns_string!(" 1").to_bool());
+    assert!(!ns_string!("no").to_bool());
+    assert!(!ns_string!("0").to_bool());
+    assert!(!ns_string!("").to_bool());
+}
+
+#[test]
+fn test_common_prefix() {
+    use crate::NSStringCompareOptions;
+
+    let a = ns_string!("filename");
+    let b = ns_string!("filepath");
+    let prefix = a.common_prefix(b, NSStringCompareOptions::empty());
+    assert_eq!(prefix.to_string(), "file");
+
+    let upper = ns_string!("FILEPATH");
+    let prefix = a.common_prefix(upper, NSStringCompareOptions::empty());
+    assert_eq!(prefix.to_string(), "");
+    let prefix = a.common_prefix(upper, NSStringCompareOptions::CaseInsensitiveSearch);
+    assert_eq!(prefix.to_string(), "file");
+
+    let prefix = a.common_prefix(ns_string!("other"), NSStringCompareOptions::empty());
+    assert_eq!(prefix.to_string(), "");
+}
+
+#[test]
+#[cfg(feature = "NSDictionary")]
+fn test_replacing_all() {
+    use crate::NSDictionary;
+
+    let substitutions = NSDictionary::from_slices(
+        &[ns_string!("{first}"), ns_string!("{last}"), ns_string!("")],
+        &[ns_string!("Jane"), ns_string!("Doe"), ns_string!("?")],
+    );
+    let s = ns_string!("{last}, {first} {last}");
+    assert_eq!(s.replacing_all(&substitutions).to_string(), "Doe, Jane Doe");
+
+    // Inserted values are not replaced again.
+    let substitutions = NSDictionary::from_slices(
+        &[ns_string!("a"), ns_string!("b")],
+        &[ns_string!("b"), ns_string!("a")],
+    );
+    let s = ns_string!("aabba");
+    assert_eq!(s.replacing_all(&substitutions).to_string(), "bbaab");
+
+    // The longest key wins.
+    let substitutions = NSDictionary::from_slices(
+        &[ns_string!("ab"), ns_string!("abc")],
+        &[ns_string!("1"), ns_string!("2")],
+    );
+    let s = ns_string!("abcab\u{e6}");
+    assert_eq!(s.replacing_all(&substitutions).to_string(), "21\u{e6}");
+
+    let empty = NSDictionary::new();
+    let s = ns_string!("unchanged");
+    assert_eq!(s.replacing_all(&empty).to_string(), "unchanged");
+}
+
 #[test]
 #[allow(clippy::nonminimal_bool)]
 #[cfg(feature = "NSObjCRuntime")]
@@ -194,6 +294,54 @@ fn test_cmp() {
     assert!(s < longer);
 }
 
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+fn test_compare_options() {
+    use core::cmp::Ordering;
+
+    use crate::NSStringCompareOptions;
+
+    let a = ns_string!("File10");
+    let b = ns_string!("File2");
+
+    // Plain comparison is character-by-character.
+    assert_eq!(a.cmp(b), Ordering::Less);
+    assert_eq!(Ordering::from(a.compare(b)), Ordering::Less);
+
+    // Numeric and localized comparison use the value of the digits.
+    let numeric = a.compare_options(b, NSStringCompareOptions::NumericSearch);
+    assert_eq!(Ordering::from(numeric), Ordering::Greater);
+    assert_eq!(a.localized_standard_compare(b), Ordering::Greater);
+    assert_eq!(b.localized_standard_compare(a), Ordering::Less);
+
+    let lower = ns_string!("file");
+    let upper = ns_string!("FILE");
+    assert_ne!(lower.cmp(upper), Ordering::Equal);
+    let insensitive = lower.compare_options(upper, NSStringCompareOptions::CaseInsensitiveSearch);
+    assert_eq!(Ordering::from(insensitive), Ordering::Equal);
+}
+
+#[test]
+#[cfg(all(feature = "NSArray", feature = "NSEnumerator"))]
+fn test_split() {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    fn split(s: &NSString, separator: &NSString) -> Vec<String> {
+        s.split(separator).map(|part| part.to_string()).collect()
+    }
+
+    assert_eq!(split(ns_string!("a,,b"), ns_string!(",")), ["a", "", "b"]);
+    assert_eq!(split(ns_string!(",a,"), ns_string!(",")), ["", "a", ""]);
+    assert_eq!(
+        split(ns_string!("a::b::c"), ns_string!("::")),
+        ["a", "b", "c"]
+    );
+    assert_eq!(split(ns_string!("abc"), ns_string!(",")), ["abc"]);
+    assert_eq!(split(ns_string!(""), ns_string!(",")), [""]);
+    assert_eq!(split(ns_string!("日,本"), ns_string!(",")), ["日", "本"]);
+}
+
 #[test]
 #[cfg(feature = "NSPathUtilities")]
 fn test_append() {
@@ -224,6 +372,204 @@ fn test_append() {
     );
 }
 
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_truncated_to() {
+    let ellipsis = ns_string!("…");
+
+    let s = NSString::from_str("🦀🦀🦀");
+    assert_eq!(s.truncated_to(0, ellipsis).to_string(), "…");
+    assert_eq!(s.truncated_to(2, ellipsis).to_string(), "🦀🦀…");
+    assert_eq!(s.truncated_to(3, ellipsis).to_string(), "🦀🦀🦀");
+    assert_eq!(s.truncated_to(10, ellipsis).to_string(), "🦀🦀🦀");
+
+    // Combining marks stay with their base character.
+    let s = NSString::from_str("e\u{301}e\u{301}e\u{301}");
+    assert_eq!(s.truncated_to(1, ellipsis).to_string(), "e\u{301}…");
+
+    let s = NSString::new();
+    assert_eq!(s.truncated_to(0, ellipsis).to_string(), "");
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_padding() {
+    let s = ns_string!("ab");
+    assert_eq!(s.pad_left(5, ' ').to_string(), "   ab");
+    assert_eq!(s.pad_right(5, ' ').to_string(), "ab   ");
+    assert_eq!(s.center(5, '-').to_string(), "-ab--");
+    assert_eq!(s.center(6, '-').to_string(), "--ab--");
+
+    // Characters made up of several code units count as one.
+    let s = ns_string!("e\u{301}\u{1f980}");
+    assert_eq!(s.pad_left(4, '.').to_string(), "..e\u{301}\u{1f980}");
+    assert_eq!(
+        s.pad_right(3, '\u{1f980}').to_string(),
+        "e\u{301}\u{1f980}\u{1f980}"
+    );
+
+    // No-op when the string is already at least as wide.
+    let s = ns_string!("hello");
+    assert_eq!(s.pad_left(3, ' ').to_string(), "hello");
+    assert_eq!(s.pad_right(5, ' ').to_string(), "hello");
+    assert_eq!(s.center(0, ' ').to_string(), "hello");
+
+    assert_eq!(ns_string!("").center(2, 'x').to_string(), "xx");
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_display_width() {
+    assert_eq!(ns_string!("").display_width(), 0);
+    assert_eq!(ns_string!("hello").display_width(), 5);
+    assert_eq!(ns_string!("a\tb").display_width(), 2);
+    // Combining marks don't take up any extra space.
+    assert_eq!(NSString::from_str("e\u{301}").display_width(), 1);
+
+    assert_eq!(ns_string!("中文").display_width(), 4);
+    assert_eq!(ns_string!("日本語abc").display_width(), 9);
+    assert_eq!(ns_string!("한국어").display_width(), 6);
+    assert_eq!(ns_string!("ｆｕｌｌ").display_width(), 8);
+
+    assert_eq!(ns_string!("🦀").display_width(), 2);
+    assert_eq!(ns_string!("a🦀b").display_width(), 4);
+    assert_eq!(NSString::from_str("❤\u{fe0f}").display_width(), 2);
+
+    // GNUStep does not treat these as single composed character sequences.
+    if cfg!(not(feature = "gnustep-1-7")) {
+        assert_eq!(NSString::from_str("👍🏽").display_width(), 2);
+        assert_eq!(NSString::from_str("👨‍👩‍👧").display_width(), 2);
+        assert_eq!(ns_string!("🇸🇪").display_width(), 2);
+    }
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_line_and_column() {
+    let s = NSString::from_str("ab\ncd\r\nef\u{2028}g\u{2029}\rh\n");
+    // Start of each line.
+    assert_eq!(s.line_and_column(0), (1, 1));
+    assert_eq!(s.line_and_column(3), (2, 1));
+    assert_eq!(s.line_and_column(7), (3, 1));
+    assert_eq!(s.line_and_column(10), (4, 1));
+    assert_eq!(s.line_and_column(12), (5, 1));
+    assert_eq!(s.line_and_column(13), (6, 1));
+    // Mid-line, and on the line terminators.
+    assert_eq!(s.line_and_column(1), (1, 2));
+    assert_eq!(s.line_and_column(2), (1, 3));
+    assert_eq!(s.line_and_column(4), (2, 2));
+    assert_eq!(s.line_and_column(6), (2, 4));
+    assert_eq!(s.line_and_column(9), (3, 3));
+    // End of the string, after the trailing newline.
+    assert_eq!(s.line_and_column(s.len_utf16()), (7, 1));
+
+    assert_eq!(NSString::new().line_and_column(0), (1, 1));
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_line_and_column_end_of_string() {
+    // Without a trailing newline, the end is on the last line.
+    assert_eq!(NSString::from_str("abc").line_and_column(3), (1, 4));
+    assert_eq!(NSString::from_str("foo\r\nbar").line_and_column(8), (2, 4));
+    assert_eq!(NSString::from_str("a\nb").line_and_column(3), (2, 2));
+
+    // With a trailing newline, the end is at the start of a new line.
+    assert_eq!(NSString::from_str("abc\n").line_and_column(4), (2, 1));
+    assert_eq!(NSString::from_str("abc\r\n").line_and_column(5), (2, 1));
+    assert_eq!(NSString::from_str("abc\r").line_and_column(4), (2, 1));
+    assert_eq!(NSString::from_str("abc\u{2029}").line_and_column(4), (2, 1));
+    assert_eq!(NSString::from_str("\n").line_and_column(1), (2, 1));
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+#[should_panic = "out of bounds"]
+fn test_line_and_column_out_of_bounds() {
+    let _ = NSString::from_str("abc").line_and_column(4);
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_replacing_in_range() {
+    let s = NSString::from_str("abcdef");
+    let x = ns_string!("XYZ");
+    assert_eq!(s.replacing_in_range(2..4, x).to_string(), "abXYZef");
+    assert_eq!(s.replacing_in_range(0..0, x).to_string(), "XYZabcdef");
+    assert_eq!(s.replacing_in_range(6..6, x).to_string(), "abcdefXYZ");
+    assert_eq!(s.replacing_in_range(0..6, ns_string!("")).to_string(), "");
+    // The original is untouched.
+    assert_eq!(s.to_string(), "abcdef");
+
+    // Ranges are in UTF-16 code units.
+    let s = NSString::from_str("a🦀b");
+    assert_eq!(s.replacing_in_range(1..3, x).to_string(), "aXYZb");
+
+    assert_eq!(s.try_replacing_in_range(0..5, x), None);
+    assert_eq!(s.try_replacing_in_range(5..5, x), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let range = 3..2;
+    assert_eq!(s.try_replacing_in_range(range, x), None);
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+#[should_panic = "out of bounds"]
+fn test_replacing_in_range_out_of_bounds() {
+    let _ = NSString::from_str("abc").replacing_in_range(2..4, ns_string!("x"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(unix)]
+#[cfg(all(feature = "NSFileManager", feature = "NSPathUtilities"))]
+fn test_path_roundtrip() {
+    use std::path::Path;
+
+    // Use decomposed characters, since that is what the file system
+    // representation uses on Apple platforms.
+    let path = Path::new("/tmp/lo\u{308}o\u{308}ps/🦀.txt");
+    let string = NSString::from_path(path);
+    assert_eq!(string.to_path_buf(), path);
+
+    let path = Path::new("relative/path");
+    assert_eq!(NSString::from_path(path).to_string(), "relative/path");
+    assert_eq!(NSString::from_path(path).to_path_buf(), path);
+
+    assert_eq!(NSString::from_path(Path::new("")).to_string(), "");
+    assert_eq!(NSString::new().to_path_buf(), Path::new(""));
+}
+
+#[test]
+#[cfg(feature = "NSPathUtilities")]
+fn test_tilde_in_path() {
+    let home = crate::NSHomeDirectory();
+    let path = home.stringByAppendingPathComponent(ns_string!("Documents/file.txt"));
+
+    let abbreviated = path.stringByAbbreviatingWithTildeInPath();
+    assert_eq!(abbreviated.to_string(), "~/Documents/file.txt");
+    assert_eq!(abbreviated.stringByExpandingTildeInPath(), path);
+
+    assert_eq!(ns_string!("~").stringByExpandingTildeInPath(), home);
+
+    // Paths outside the home directory are left untouched.
+    let path = ns_string!("/tmp/file.txt");
+    assert_eq!(&*path.stringByAbbreviatingWithTildeInPath(), path);
+    assert_eq!(&*path.stringByExpandingTildeInPath(), path);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(target_vendor = "apple")]
+#[cfg(all(feature = "NSFileManager", feature = "NSPathUtilities"))]
+fn test_path_decomposed() {
+    use std::path::Path;
+
+    // The file system representation is decomposed.
+    let string = NSString::from_str("/tmp/\u{e4}");
+    assert_eq!(string.to_path_buf(), Path::new("/tmp/a\u{308}"));
+}
+
 #[test]
 fn test_macro() {
     macro_rules! test {
@@ -299,3 +645,20 @@ fn toll_free_bridging() {
 
     assert_eq!(cf_string.to_string(), string.to_string());
 }
+
+#[test]
+fn downcast_from_nsobject() {
+    use objc2::rc::Retained;
+
+    use crate::NSObject;
+
+    let obj: Retained<NSObject> = NSString::from_str("abc").into_super();
+    let string = obj.downcast_ref::<NSString>().unwrap();
+    assert_eq!(string.to_string(), "abc");
+
+    let string = obj.downcast::<NSString>().unwrap();
+    assert_eq!(string.to_string(), "abc");
+
+    let obj = NSObject::new();
+    assert!(obj.downcast_ref::<NSString>().is_none());
+}
@@ -0,0 +1,154 @@
+#![cfg(all(
+    feature = "async",
+    feature = "NSData",
+    feature = "NSError",
+    feature = "NSString",
+    feature = "NSURL",
+    feature = "NSURLRequest",
+    feature = "NSURLResponse",
+    feature = "NSURLSession"
+))]
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::task::Wake;
+use std::thread::{self, JoinHandle, Thread};
+
+use crate::{
+    ns_string, NSData, NSHTTPURLResponse, NSMutableURLRequest, NSString, NSURLRequest,
+    NSURLSession, NSURL,
+};
+
+/// Minimal executor, that parks the current thread until woken.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker: Waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Serve a single HTTP request on localhost.
+///
+/// The server responds with the method and the body of the request.
+fn serve_once() -> (String, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/path", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let method = request_line.split(' ').next().unwrap().to_string();
+
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            let (name, value) = line.split_once(':').unwrap();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+
+        let mut response = format!("{method} ").into_bytes();
+        response.extend(body);
+        let mut stream = reader.into_inner();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            response.len(),
+        )
+        .unwrap();
+        stream.write_all(&response).unwrap();
+    });
+
+    (url, handle)
+}
+
+#[test]
+#[cfg_attr(feature = "gnustep-1-7", ignore = "NSURLSession is not reliable")]
+fn fetch() {
+    let (url, server) = serve_once();
+    let url = NSURL::URLWithString(&NSString::from_str(&url)).unwrap();
+    let request = NSURLRequest::requestWithURL(&url);
+
+    let session = NSURLSession::sharedSession();
+    let (response, data) = block_on(session.fetch(&request)).unwrap();
+    server.join().unwrap();
+
+    let response = response.downcast::<NSHTTPURLResponse>().unwrap();
+    assert_eq!(response.statusCode(), 200);
+    assert_eq!(data.to_vec(), b"GET ");
+}
+
+#[test]
+#[cfg_attr(feature = "gnustep-1-7", ignore = "NSURLSession is not reliable")]
+fn upload() {
+    let (url, server) = serve_once();
+    let url = NSURL::URLWithString(&NSString::from_str(&url)).unwrap();
+    let request = NSMutableURLRequest::requestWithURL(&url);
+    request.setHTTPMethod(ns_string!("POST"));
+
+    let session = NSURLSession::sharedSession();
+    let data = NSData::with_bytes(b"hello");
+    let (_, data) = block_on(session.upload(&request, &data)).unwrap();
+    server.join().unwrap();
+
+    assert_eq!(data.to_vec(), b"POST hello");
+}
+
+#[test]
+#[cfg_attr(feature = "gnustep-1-7", ignore = "NSURLSession is not reliable")]
+fn fetch_bytes() {
+    let (url, server) = serve_once();
+    let url = NSURL::URLWithString(&NSString::from_str(&url)).unwrap();
+
+    let session = NSURLSession::sharedSession();
+    let bytes = block_on(session.fetch_bytes(&url)).unwrap();
+    server.join().unwrap();
+
+    assert_eq!(bytes, b"GET ");
+}
+
+#[test]
+#[cfg_attr(feature = "gnustep-1-7", ignore = "NSURLSession is not reliable")]
+fn connection_refused() {
+    // Bind and immediately close to get a port that nothing listens on.
+    let addr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let url = NSString::from_str(&format!("http://{addr}/"));
+    let url = NSURL::URLWithString(&url).unwrap();
+
+    let session = NSURLSession::sharedSession();
+    let error = block_on(session.fetch_bytes(&url)).unwrap_err();
+    assert_eq!(error.domain().to_string(), "NSURLErrorDomain");
+}
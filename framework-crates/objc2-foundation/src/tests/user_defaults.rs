@@ -0,0 +1,56 @@
+#![cfg(all(feature = "NSUserDefaults", feature = "NSString"))]
+use alloc::string::ToString;
+
+use objc2::rc::Retained;
+use objc2::AnyThread;
+
+use crate::{ns_string, NSUserDefaults};
+
+fn defaults() -> Retained<NSUserDefaults> {
+    // Use a separate suite to avoid interfering with the test binary's own
+    // defaults.
+    let suite = ns_string!("io.github.madsmtm.objc2-foundation.tests");
+    NSUserDefaults::initWithSuiteName(NSUserDefaults::alloc(), Some(suite)).unwrap()
+}
+
+#[test]
+fn typed_values() {
+    let defaults = defaults();
+
+    let key = ns_string!("bool");
+    assert!(!defaults.boolForKey(key));
+    defaults.setBool_forKey(true, key);
+    assert!(defaults.boolForKey(key));
+    defaults.removeObjectForKey(key);
+    assert!(!defaults.boolForKey(key));
+
+    let key = ns_string!("integer");
+    defaults.setInteger_forKey(-42, key);
+    assert_eq!(defaults.integerForKey(key), -42);
+    defaults.removeObjectForKey(key);
+    assert_eq!(defaults.integerForKey(key), 0);
+
+    let key = ns_string!("string");
+    assert_eq!(defaults.stringForKey(key), None);
+    defaults.set_string(ns_string!("abc"), key);
+    assert_eq!(defaults.stringForKey(key).unwrap().to_string(), "abc");
+    // Strings are not converted to other types.
+    assert_eq!(defaults.integerForKey(key), 0);
+    defaults.removeObjectForKey(key);
+    assert_eq!(defaults.stringForKey(key), None);
+}
+
+#[test]
+#[cfg(feature = "NSData")]
+fn data() {
+    use crate::NSData;
+
+    let defaults = defaults();
+    let key = ns_string!("data");
+    defaults.set_data(&NSData::with_bytes(&[1, 2, 3]), key);
+    assert_eq!(defaults.dataForKey(key).unwrap().to_vec(), [1, 2, 3]);
+    // Data is not a string.
+    assert_eq!(defaults.stringForKey(key), None);
+    defaults.removeObjectForKey(key);
+    assert_eq!(defaults.dataForKey(key), None);
+}
@@ -25,6 +25,23 @@ fn test_bytes() {
     assert_eq!(uuid.as_bytes(), [10; 16]);
 }
 
+#[test]
+#[cfg(feature = "NSString")]
+#[ignore = "encoding depends on Foundation version"]
+fn test_byte_order() {
+    let bytes = [
+        0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd,
+        0xef,
+    ];
+    let uuid = NSUUID::from_bytes(bytes);
+    assert_eq!(format!("{uuid}"), "12345678-9ABC-DEF0-0123-456789ABCDEF");
+    assert_eq!(uuid.as_bytes(), bytes);
+
+    let string = crate::NSString::from_str("12345678-9ABC-DEF0-0123-456789ABCDEF");
+    let uuid = NSUUID::from_string(&string).unwrap();
+    assert_eq!(uuid.as_bytes(), bytes);
+}
+
 #[test]
 #[cfg(feature = "NSString")]
 #[ignore = "encoding depends on Foundation version"]
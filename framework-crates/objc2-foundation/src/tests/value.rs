@@ -92,8 +92,14 @@ fn nsrect() {
     let rect = NSRect::new(NSPoint::new(1.0, 2.0), NSSize::new(3.0, 4.0));
     let val = NSValue::new(rect);
     assert_eq!(val.get_rect(), Some(rect));
+    assert_eq!(val.get_point(), None);
+    assert_eq!(val.get_size(), None);
     #[cfg(not(feature = "gnustep-1-7"))]
     assert_eq!(unsafe { val.get::<NSRect>() }, rect);
+
+    // Has the same layout as `NSRect`, but a different encoding.
+    let val = NSValue::new([1.0f64, 2.0, 3.0, 4.0]);
+    assert_eq!(val.get_rect(), None);
 }
 
 #[test]
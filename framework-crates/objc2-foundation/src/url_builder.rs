@@ -0,0 +1,239 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use objc2::rc::Retained;
+
+use crate::{NSNumber, NSString, NSURLComponents, NSURL};
+
+/// A builder for [`NSURL`], that takes care of percent-encoding each part
+/// of the URL.
+///
+/// This uses [`NSURLComponents`] internally.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::URLBuilder;
+///
+/// let url = URLBuilder::new()
+///     .scheme("https")
+///     .host("example.com")
+///     .path("/search")
+///     .query_item("q", "cats & dogs")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     url.absoluteString().unwrap().to_string(),
+///     "https://example.com/search?q=cats%20%26%20dogs",
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct URLBuilder {
+    scheme: Option<String>,
+    host: Option<String>,
+    port: Option<u16>,
+    path: String,
+    query: Vec<(String, String)>,
+    fragment: Option<String>,
+}
+
+impl URLBuilder {
+    /// Create a new, empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the scheme, such as `"https"` or `"file"`.
+    ///
+    /// The scheme is not percent-encoded, if it contains characters that are
+    /// not allowed in a scheme, [`build`][Self::build] returns `None`.
+    #[doc(alias = "setScheme:")]
+    pub fn scheme(&mut self, scheme: &str) -> &mut Self {
+        self.scheme = Some(scheme.into());
+        self
+    }
+
+    /// Set the host.
+    ///
+    /// Use an empty host to create URLs such as `file:///tmp`, that have an
+    /// authority component without a host.
+    #[doc(alias = "setHost:")]
+    pub fn host(&mut self, host: &str) -> &mut Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Set the port.
+    #[doc(alias = "setPort:")]
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Set the path.
+    ///
+    /// If a host or port is set, the path must be empty or start with `/`,
+    /// otherwise [`build`][Self::build] returns `None`.
+    #[doc(alias = "setPath:")]
+    pub fn path(&mut self, path: &str) -> &mut Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Append a `key=value` pair to the query.
+    ///
+    /// Both the key and the value are percent-encoded, including characters
+    /// such as `&`, `=` and `+` that would otherwise have special meaning
+    /// inside the query.
+    #[doc(alias = "setQueryItems:")]
+    pub fn query_item(&mut self, key: &str, value: &str) -> &mut Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the fragment.
+    #[doc(alias = "setFragment:")]
+    pub fn fragment(&mut self, fragment: &str) -> &mut Self {
+        self.fragment = Some(fragment.into());
+        self
+    }
+
+    /// Create the URL.
+    ///
+    /// Returns `None` if the parts do not form a valid URL, for example if
+    /// the scheme is invalid, or if a host is set and the path is relative.
+    #[doc(alias = "URL")]
+    pub fn build(&self) -> Option<Retained<NSURL>> {
+        let components = NSURLComponents::new();
+
+        if let Some(scheme) = &self.scheme {
+            // `setScheme:` throws an exception on invalid schemes.
+            if !is_valid_scheme(scheme) {
+                return None;
+            }
+            components.setScheme(Some(&NSString::from_str(scheme)));
+        }
+        if let Some(host) = &self.host {
+            components.setHost(Some(&NSString::from_str(host)));
+        }
+        if let Some(port) = self.port {
+            components.setPort(Some(&NSNumber::new_u16(port)));
+        }
+        components.setPath(Some(&NSString::from_str(&self.path)));
+        if !self.query.is_empty() {
+            // We do the encoding of the query ourselves, since `NSURLComponents`
+            // leaves e.g. `+` as-is, which many servers decode as a space.
+            let mut query = String::new();
+            for (i, (key, value)) in self.query.iter().enumerate() {
+                if i != 0 {
+                    query.push('&');
+                }
+                percent_encode(&mut query, key);
+                query.push('=');
+                percent_encode(&mut query, value);
+            }
+            components.setPercentEncodedQuery(Some(&NSString::from_str(&query)));
+        }
+        if let Some(fragment) = &self.fragment {
+            components.setFragment(Some(&NSString::from_str(fragment)));
+        }
+
+        components.URL()
+    }
+}
+
+/// `ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`, see RFC 3986 section 3.1.
+fn is_valid_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Percent-encode everything except the unreserved characters from RFC 3986
+/// section 2.3.
+fn percent_encode(buf: &mut String, s: &str) {
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            buf.push(byte as char);
+        } else {
+            write!(buf, "%{byte:02X}").unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn scheme_validation() {
+        assert!(is_valid_scheme("https"));
+        assert!(is_valid_scheme("x-my.app+v2"));
+        assert!(!is_valid_scheme(""));
+        assert!(!is_valid_scheme("1abc"));
+        assert!(!is_valid_scheme("ht tp"));
+    }
+
+    #[test]
+    fn encoding() {
+        let mut buf = String::new();
+        percent_encode(&mut buf, "a-z_0.9~ &=+/?é");
+        assert_eq!(buf, "a-z_0.9~%20%26%3D%2B%2F%3F%C3%A9");
+    }
+
+    fn to_string(url: &NSURL) -> String {
+        url.absoluteString().unwrap().to_string()
+    }
+
+    #[test]
+    fn https() {
+        let url = URLBuilder::new()
+            .scheme("https")
+            .host("example.com")
+            .port(8443)
+            .path("/a b/ü")
+            .query_item("q", "1+1=2")
+            .query_item("empty", "")
+            .fragment("top")
+            .build()
+            .unwrap();
+        assert_eq!(
+            to_string(&url),
+            "https://example.com:8443/a%20b/%C3%BC?q=1%2B1%3D2&empty=#top",
+        );
+        assert_eq!(url.host().unwrap().to_string(), "example.com");
+        assert_eq!(url.path().unwrap().to_string(), "/a b/ü");
+        assert_eq!(url.query().unwrap().to_string(), "q=1%2B1%3D2&empty=");
+    }
+
+    #[test]
+    fn file() {
+        let url = URLBuilder::new()
+            .scheme("file")
+            .host("")
+            .path("/tmp/file name.txt")
+            .build()
+            .unwrap();
+        assert_eq!(to_string(&url), "file:///tmp/file%20name.txt");
+        assert!(url.isFileURL());
+    }
+
+    #[test]
+    fn invalid() {
+        // Invalid scheme.
+        assert_eq!(URLBuilder::new().scheme("a b").build(), None);
+
+        // Relative path together with a host.
+        let mut builder = URLBuilder::new();
+        builder.scheme("https").host("example.com").path("relative");
+        assert_eq!(builder.build(), None);
+
+        // Path that would be interpreted as a host.
+        assert_eq!(URLBuilder::new().path("//example.com").build(), None);
+    }
+}
@@ -0,0 +1,179 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::Mutex;
+
+use block2::{DynBlock, RcBlock};
+use objc2::rc::Retained;
+
+use crate::{NSData, NSError, NSURLRequest, NSURLResponse, NSURLSession, NSURLSessionTask, NSURL};
+
+type CompletionHandler = DynBlock<dyn Fn(*mut NSData, *mut NSURLResponse, *mut NSError) + 'static>;
+
+type Completion = (
+    Option<Retained<NSData>>,
+    Option<Retained<NSURLResponse>>,
+    Option<Retained<NSError>>,
+);
+
+#[derive(Default)]
+struct State {
+    completion: Option<Completion>,
+    waker: Option<Waker>,
+}
+
+// SAFETY: The objects in the completion are not accessed by the completion
+// handler after they have been stored, so they are only ever moved from the
+// session's delegate queue to the thread that polls the future. The data,
+// response and error objects that `NSURLSession` creates are immutable.
+unsafe impl Send for State {}
+
+/// A future that resolves when the completion handler of a task is called.
+///
+/// The task is cancelled when the future is dropped.
+struct TaskFuture {
+    task: Retained<NSURLSessionTask>,
+    state: Arc<Mutex<State>>,
+}
+
+impl TaskFuture {
+    fn new(create: impl FnOnce(&CompletionHandler) -> Retained<NSURLSessionTask>) -> Self {
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let block_state = Arc::clone(&state);
+        let block = RcBlock::new(
+            move |data: *mut NSData, response: *mut NSURLResponse, error: *mut NSError| {
+                // SAFETY: The objects are either NULL, or valid for the
+                // duration of the block invocation, and we retain them to
+                // keep them alive afterwards.
+                let completion = unsafe {
+                    (
+                        Retained::retain(data),
+                        Retained::retain(response),
+                        Retained::retain(error),
+                    )
+                };
+                let waker = {
+                    let mut state = block_state.lock().unwrap();
+                    state.completion = Some(completion);
+                    state.waker.take()
+                };
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            },
+        );
+
+        let task = create(&block);
+        task.resume();
+        Self { task, state }
+    }
+}
+
+impl Future for TaskFuture {
+    type Output = Result<(Retained<NSURLResponse>, Retained<NSData>), Retained<NSError>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.completion.take() {
+            Some((_, _, Some(error))) => Poll::Ready(Err(error)),
+            Some((data, response, None)) => {
+                let response = response.expect("task completed without response or error");
+                // The data may be NULL if the response had no body.
+                let data = data.unwrap_or_else(NSData::new);
+                Poll::Ready(Ok((response, data)))
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for TaskFuture {
+    fn drop(&mut self) {
+        // Does nothing if the task has already completed.
+        self.task.cancel();
+    }
+}
+
+/// Asynchronous loading.
+///
+/// These create a task with a completion handler, resume it, and resolve
+/// once the completion handler is called. Dropping the returned future
+/// before it has resolved cancels the task.
+///
+/// The futures do not depend on any specific executor. The completion
+/// handler is called on the session's delegate queue, which wakes the task
+/// that is awaiting the future.
+///
+/// Note that a response with an HTTP error status code such as 404 is not
+/// an error, use [`NSHTTPURLResponse::statusCode`] to check it.
+///
+/// [`NSHTTPURLResponse::statusCode`]: crate::NSHTTPURLResponse::statusCode
+///
+///
+/// # Examples
+///
+/// ```no_run
+/// use objc2::rc::Retained;
+/// use objc2_foundation::{NSError, NSHTTPURLResponse, NSURLRequest, NSURLSession, NSURL};
+///
+/// async fn status_code(url: &NSURL) -> Result<isize, Retained<NSError>> {
+///     let session = NSURLSession::sharedSession();
+///     let request = NSURLRequest::requestWithURL(url);
+///     let (response, _data) = session.fetch(&request).await?;
+///     let response = response.downcast::<NSHTTPURLResponse>().unwrap();
+///     Ok(response.statusCode())
+/// }
+/// ```
+impl NSURLSession {
+    /// Load the contents of the request.
+    #[doc(alias = "dataTaskWithRequest:completionHandler:")]
+    pub async fn fetch(
+        &self,
+        request: &NSURLRequest,
+    ) -> Result<(Retained<NSURLResponse>, Retained<NSData>), Retained<NSError>> {
+        TaskFuture::new(|block| {
+            // SAFETY: The completion handler is safe to call from any
+            // thread.
+            let task = unsafe { self.dataTaskWithRequest_completionHandler(request, block) };
+            task.into_super()
+        })
+        .await
+    }
+
+    /// Upload the data as the body of the request, and load the response.
+    ///
+    /// The body of the request itself is ignored.
+    #[doc(alias = "uploadTaskWithRequest:fromData:completionHandler:")]
+    pub async fn upload(
+        &self,
+        request: &NSURLRequest,
+        data: &NSData,
+    ) -> Result<(Retained<NSURLResponse>, Retained<NSData>), Retained<NSError>> {
+        TaskFuture::new(|block| {
+            // SAFETY: Same as above.
+            let task = unsafe {
+                self.uploadTaskWithRequest_fromData_completionHandler(request, data, block)
+            };
+            task.into_super().into_super()
+        })
+        .await
+    }
+
+    /// Load the contents of the URL into a `Vec`.
+    #[doc(alias = "dataTaskWithURL:completionHandler:")]
+    pub async fn fetch_bytes(&self, url: &NSURL) -> Result<Vec<u8>, Retained<NSError>> {
+        let (_response, data) = TaskFuture::new(|block| {
+            // SAFETY: Same as above.
+            let task = unsafe { self.dataTaskWithURL_completionHandler(url, block) };
+            task.into_super()
+        })
+        .await?;
+        Ok(data.to_vec())
+    }
+}
@@ -1,6 +1,61 @@
+#[cfg(feature = "NSString")]
+use objc2::runtime::AnyObject;
+
+#[cfg(feature = "NSData")]
+use crate::NSData;
+#[cfg(feature = "NSString")]
+use crate::NSString;
 use crate::NSUserDefaults;
 
 // Documented to be thread-safe, and should be, it's a singleton that's
 // accessible from any thread using `standardUserDefaults`.
 unsafe impl Send for NSUserDefaults {}
 unsafe impl Sync for NSUserDefaults {}
+
+/// Typed setters.
+///
+/// The getters (`boolForKey`, `integerForKey`, `stringForKey`, `dataForKey`
+/// and so on) and setters for primitive values (`setBool_forKey`,
+/// `setInteger_forKey`, ...) are already typed. Objects are set with
+/// `setObject:forKey:`, which is unsafe since it accepts any object, but
+/// only property list objects can be stored; these helpers provide safe
+/// alternatives for common types.
+#[cfg(feature = "NSString")]
+impl NSUserDefaults {
+    /// Set the string value of the key.
+    ///
+    /// Use `removeObjectForKey` to remove the value again.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSUserDefaults};
+    ///
+    /// let defaults = NSUserDefaults::standardUserDefaults();
+    /// let key = ns_string!("objc2-doc-example-name");
+    ///
+    /// defaults.set_string(ns_string!("Ferris"), key);
+    /// assert_eq!(defaults.stringForKey(key).unwrap().to_string(), "Ferris");
+    ///
+    /// defaults.removeObjectForKey(key);
+    /// assert_eq!(defaults.stringForKey(key), None);
+    /// ```
+    #[doc(alias = "setObject:forKey:")]
+    pub fn set_string(&self, value: &NSString, key: &NSString) {
+        let value: &AnyObject = value;
+        // SAFETY: Strings are property list objects.
+        unsafe { self.setObject_forKey(Some(value), key) }
+    }
+
+    /// Set the data value of the key.
+    ///
+    /// Use `removeObjectForKey` to remove the value again.
+    #[doc(alias = "setObject:forKey:")]
+    #[cfg(feature = "NSData")]
+    pub fn set_data(&self, value: &NSData, key: &NSString) {
+        let value: &AnyObject = value;
+        // SAFETY: Data objects are property list objects.
+        unsafe { self.setObject_forKey(Some(value), key) }
+    }
+}
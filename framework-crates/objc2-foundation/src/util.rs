@@ -45,3 +45,23 @@ pub(crate) unsafe fn display_string(string: &NSObject, f: &mut fmt::Formatter<'_
     // somehow check that the string is immutable?).
     autoreleasepool_leaking(|pool| fmt::Display::fmt(unsafe { nsstring_to_str(string, pool) }, f))
 }
+
+/// Find the first index in `0..len` for which `is_before` returns `false`,
+/// assuming that `is_before` is `true` for a prefix of the range, and `false`
+/// for the rest.
+///
+/// Like `<[T]>::partition_point`, but for collections that cannot be viewed
+/// as a slice.
+pub(crate) fn partition_point(len: usize, mut is_before: impl FnMut(usize) -> bool) -> usize {
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if is_before(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
@@ -40,6 +40,10 @@ impl NSUUID {
 
     /// Create a new `NSUUID` from the given bytes.
     ///
+    /// The bytes are in the order in which they appear in the string
+    /// representation of the UUID (i.e. the fields are big-endian), which is
+    /// the same order that the `uuid` crate uses.
+    ///
     /// NOTE: The headers describe `initWithUUIDBytes:` as taking `uuid_t`,
     /// but their actual implementation may use something else.
     ///
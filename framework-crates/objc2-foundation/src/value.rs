@@ -109,6 +109,10 @@ impl NSValue {
         }
     }
 
+    /// Retrieve the [`NSPoint`][crate::NSPoint] contained in the `NSValue`.
+    ///
+    /// Returns `None` if the value does not store an `NSPoint` (also known as
+    /// `CGPoint`). Use [`NSValue::new`] to create such a value.
     #[cfg(all(feature = "NSGeometry", feature = "objc2-core-foundation"))]
     pub fn get_point(&self) -> Option<crate::NSPoint> {
         if self.contains_encoding::<crate::NSPoint>() {
@@ -123,6 +127,10 @@ impl NSValue {
         }
     }
 
+    /// Retrieve the [`NSSize`][crate::NSSize] contained in the `NSValue`.
+    ///
+    /// Returns `None` if the value does not store an `NSSize` (also known as
+    /// `CGSize`). Use [`NSValue::new`] to create such a value.
     #[cfg(all(feature = "NSGeometry", feature = "objc2-core-foundation"))]
     pub fn get_size(&self) -> Option<crate::NSSize> {
         if self.contains_encoding::<crate::NSSize>() {
@@ -133,6 +141,10 @@ impl NSValue {
         }
     }
 
+    /// Retrieve the [`NSRect`][crate::NSRect] contained in the `NSValue`.
+    ///
+    /// Returns `None` if the value does not store an `NSRect` (also known as
+    /// `CGRect`). Use [`NSValue::new`] to create such a value.
     #[cfg(all(feature = "NSGeometry", feature = "objc2-core-foundation"))]
     pub fn get_rect(&self) -> Option<crate::NSRect> {
         if self.contains_encoding::<crate::NSRect>() {